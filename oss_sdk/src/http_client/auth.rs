@@ -6,7 +6,7 @@ use crypto::hmac::Hmac;
 use crypto::mac::Mac;
 use crypto::sha1::Sha1;
 
-const RESOURCES: [&str; 51] = [
+const RESOURCES: [&str; 53] = [
     "acl",
     "uploads",
     "location",
@@ -58,6 +58,8 @@ const RESOURCES: [&str; 51] = [
     "callback",
     "callback-var",
     "continuation-token",
+    "versionId",
+    "versions",
 ];
 
 impl SignedRequest{
@@ -78,8 +80,8 @@ impl SignedRequest{
             .unwrap_or_default();
 
         let content_md5 = headers
-            .get("Content-MD5")
-            .map(|val| encode(val.as_str()))
+            .get("content-md5")
+            .map(|val| val.as_str())
             .unwrap_or_default();
 
         let mut oss_headers_str = String::new();
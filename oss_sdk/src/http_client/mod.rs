@@ -8,8 +8,8 @@ mod responses;
 mod sign_and_dispatch;
 
 pub use errors::DispatchError;
-pub use requests::SignedRequest;
-pub use responses::HttpResponse;
+pub use requests::{SignedRequest, SignedRequestPayload};
+pub use responses::{HttpResponse, StreamingHttpResponse};
 pub use sign_and_dispatch::SignAndDispatch;
 
 type Params = BTreeMap<String, Option<String>>;
@@ -1,6 +1,15 @@
 use super::*;
 
-#[derive(Clone, Debug, Default)]
+use crypto::{digest::Digest, md5::Md5};
+
+/// Payload of a SignedRequest, either in memory or read from a stream while sending.
+#[derive(Debug)]
+pub enum SignedRequestPayload {
+    Buffer(Box<[u8]>),
+    Stream(ByteStream),
+}
+
+#[derive(Debug, Default)]
 pub struct SignedRequest {
     pub method: &'static str,
     pub region: Region,
@@ -8,7 +17,7 @@ pub struct SignedRequest {
     pub object: String,
     pub headers: Headers,
     pub params: Params,
-    pub payload: Option<Box<[u8]>>,
+    pub payload: Option<SignedRequestPayload>,
     pub access_key_id: String,
     pub access_key_secret: String,
     pub url: String,
@@ -81,10 +90,18 @@ impl SignedRequest {
     where
         P: Into<Box<[u8]>>,
     {
-        self.payload = Some(payload.into());
-        self.payload.as_ref().unwrap().len()
+        let payload = payload.into();
+        let len = payload.len();
+        self.payload = Some(SignedRequestPayload::Buffer(payload));
+        len
     }
-    pub fn unload(&mut self) -> Option<Box<[u8]>> {
+    /// Returns the size_hint of the stream, if any.
+    pub fn load_stream(&mut self, stream: ByteStream) -> Option<usize> {
+        let size_hint = stream.size_hint();
+        self.payload = Some(SignedRequestPayload::Stream(stream));
+        size_hint
+    }
+    pub fn unload(&mut self) -> Option<SignedRequestPayload> {
         self.payload.take()
     }
     pub fn set_content_type(&mut self, content_type: String) {
@@ -96,19 +113,22 @@ impl SignedRequest {
     pub fn get_schema(&self) -> String {
         format!("{}", self.schema)
     }
-    // /// Computes and sets the Content-MD5 header based on the current payload.
-    // ///
-    // /// Has no effect if the payload is not set, or is not a buffer. Will not
-    // /// override an existing value for the `Content-MD5` header.
-    // pub fn maybe_set_content_md5_header(&mut self) {
-    //     if self.headers.contains_key("Content-MD5") {
-    //         return;
-    //     }
-    //     if let Some(SignedRequestPayload::Buffer(ref payload)) = self.payload {
-    //         let digest = Md5::digest(payload);
-    //         self.add_header("Content-MD5", &base64::encode(&*digest));
-    //     }
-    // }
+    /// Computes and sets the Content-MD5 header based on the current payload.
+    ///
+    /// Has no effect if the payload is not set, or is not a buffer. Will not
+    /// override an existing value for the `Content-MD5` header.
+    pub fn maybe_set_content_md5_header(&mut self) {
+        if self.headers.contains_key("content-md5") {
+            return;
+        }
+        if let Some(SignedRequestPayload::Buffer(ref payload)) = self.payload {
+            let mut hasher = Md5::new();
+            hasher.input(payload);
+            let mut digest = [0u8; 16];
+            hasher.result(&mut digest);
+            self.add_header("Content-MD5", base64::encode(digest));
+        }
+    }
 
     pub fn generate_url(&self) -> String {
         if self.bucket.is_empty() {
@@ -141,7 +161,7 @@ fn get_params_str(params: &Params) -> String {
             result += "&";
         }
         if let Some(_v) = v {
-            result += &format!("{}={}", k, _v);
+            result += &format!("{}={}", k, encode_param(_v));
         } else {
             result += k;
         }
@@ -149,6 +169,20 @@ fn get_params_str(params: &Params) -> String {
     result
 }
 
+/// Percent-encodes everything but the RFC 3986 unreserved characters.
+fn encode_param(val: &str) -> String {
+    let mut result = String::with_capacity(val.len());
+    for b in val.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(b as char)
+            }
+            _ => result += &format!("%{:02X}", b),
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,11 @@
 use std::pin::Pin;
 
 use bytes::Bytes;
+use futures::TryStreamExt;
 use http::{HeaderMap, StatusCode};
 
+use crate::ByteStream;
+
 /// Stores the response from a HTTP request.
 pub struct HttpResponse {
     /// Status code of HTTP Request
@@ -13,6 +16,17 @@ pub struct HttpResponse {
     pub headers: HeaderMap,
 }
 
+/// Stores the response from a HTTP request, with the body left unread as a stream.
+#[derive(Debug)]
+pub struct StreamingHttpResponse {
+    /// Status code of HTTP Request
+    pub status: StatusCode,
+    /// Contents of Response
+    pub body: ByteStream,
+    /// Response headers
+    pub headers: HeaderMap,
+}
+
 impl std::fmt::Debug for HttpResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // let mut content = String::new();
@@ -25,14 +39,31 @@ impl std::fmt::Debug for HttpResponse {
     }
 }
 impl HttpResponse {
-    pub(crate) async  fn from_resp(resp: reqwest::Response) -> Self {
+    pub(crate) async fn from_resp(resp: reqwest::Response) -> Result<Self, reqwest::Error> {
         let status = resp.status();
         let headers = resp.headers().to_owned();
-        let bytes = resp.bytes().await.unwrap();
-        Self {
+        let bytes = resp.bytes().await?;
+        Ok(Self {
             status,
             headers,
             body: Box::pin(bytes),
+        })
+    }
+}
+impl StreamingHttpResponse {
+    pub(crate) fn from_resp(resp: reqwest::Response) -> Self {
+        let status = resp.status();
+        let headers = resp.headers().to_owned();
+        let size_hint = resp.content_length().map(|len| len as usize);
+        let stream = resp.bytes_stream().map_err(std::io::Error::other);
+        let body = match size_hint {
+            Some(_size) => ByteStream::new_with_size(stream, _size),
+            None => ByteStream::new(stream),
+        };
+        Self {
+            status,
+            headers,
+            body,
         }
     }
 }
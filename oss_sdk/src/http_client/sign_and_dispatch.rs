@@ -2,7 +2,11 @@ use std::{ str::FromStr};
 
 use reqwest::{header::HeaderName, Method};
 
-use super::{errors::DispatchError, responses::HttpResponse, SignedRequest};
+use super::{
+    errors::DispatchError,
+    responses::{HttpResponse, StreamingHttpResponse},
+    SignedRequest, SignedRequestPayload,
+};
 
 use async_trait::async_trait;
 
@@ -14,34 +18,59 @@ pub trait SignAndDispatch {
         // timeout: Option<Duration>,
     ) -> Result<HttpResponse, DispatchError>;
     // ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, DispatchError>> + Send>>;
+
+    /// Same as sign_and_dispatch, but leaves the response body unread.
+    async fn sign_and_dispatch_streaming(
+        &self,
+        mut request: SignedRequest,
+    ) -> Result<StreamingHttpResponse, DispatchError>;
 }
 
 #[async_trait]
 impl SignAndDispatch for reqwest::Client {
     async fn sign_and_dispatch(
         &self,
-        mut request: SignedRequest,
+        request: SignedRequest,
         // timeout: Option<Duration>,
     ) -> Result<HttpResponse, DispatchError> {
         // ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, DispatchError>> + Send>> {
-        request.oss_sign();
-        let url = request.generate_url();
-        let mut headers = reqwest::header::HeaderMap::new();
-        for (key, val) in request.headers.iter() {
-            headers.insert(HeaderName::from_bytes(key.as_bytes())?, val.parse()?);
-        }
-        let method = Method::from_str(request.method).map_err(|_| DispatchError::InvalidMethod)?;
-        let mut request_builder = self
-            .request(method, &url)
-            .headers(headers)
-            .query(&request.params);
-        if let Some(_payload) = request.payload {
+        let ret = build_request(self, request)?.send();
+        let http_resp = HttpResponse::from_resp(ret.await?).await?;
+        Ok(http_resp)
+    }
+
+    async fn sign_and_dispatch_streaming(
+        &self,
+        request: SignedRequest,
+    ) -> Result<StreamingHttpResponse, DispatchError> {
+        let ret = build_request(self, request)?.send();
+        Ok(StreamingHttpResponse::from_resp(ret.await?))
+    }
+}
+
+fn build_request(
+    client: &reqwest::Client,
+    mut request: SignedRequest,
+) -> Result<reqwest::RequestBuilder, DispatchError> {
+    request.oss_sign();
+    let url = request.generate_url();
+    let mut headers = reqwest::header::HeaderMap::new();
+    for (key, val) in request.headers.iter() {
+        headers.insert(HeaderName::from_bytes(key.as_bytes())?, val.parse()?);
+    }
+    let method = Method::from_str(request.method).map_err(|_| DispatchError::InvalidMethod)?;
+    // Params are already encoded into the url, sub-resources without values included.
+    let mut request_builder = client.request(method, &url).headers(headers);
+    match request.payload {
+        Some(SignedRequestPayload::Buffer(_payload)) => {
             request_builder = request_builder.body(_payload.into_vec());
         }
-        let ret = request_builder.send();
-        let http_resp = HttpResponse::from_resp(ret.await?).await;
-        Ok(http_resp)
+        Some(SignedRequestPayload::Stream(_stream)) => {
+            request_builder = request_builder.body(reqwest::Body::wrap_stream(_stream));
+        }
+        None => (),
     }
+    Ok(request_builder)
 }
 
 // impl From<reqwest::Response> for HttpResponse {
@@ -10,7 +10,10 @@ pub use types::*;
 
 pub use oss::OSS_PREFIX;
 
-pub use crate::http_client::{DispatchError as OSSError, HttpResponse, SignAndDispatch};
+pub use crate::http_client::{
+    DispatchError as OSSError, HttpResponse, SignAndDispatch, SignedRequestPayload,
+    StreamingHttpResponse,
+};
 pub use crate::oss::OSSClient;
 
 pub type OssClient = OSSClient<reqwest::Client>;
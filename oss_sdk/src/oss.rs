@@ -7,7 +7,7 @@ use crypto::sha1::Sha1;
 
 use std::collections::BTreeMap;
 
-use crate::http_client::{HttpResponse, SignAndDispatch, SignedRequest};
+use crate::http_client::{HttpResponse, SignAndDispatch, SignedRequest, StreamingHttpResponse};
 
 pub const OSS_PREFIX: &str = "x-oss-meta-";
 pub const OSS_CANONOCALIZED_PREFIX: &str = "x-oss-";
@@ -55,6 +55,9 @@ impl<C: SignAndDispatch + Send + Sync> OSSClient<C> {
     pub fn get_access_key(&self) -> (&str, &str) {
         (&self.access_key_id, &self.access_key_secret)
     }
+    pub fn get_bucket(&self) -> &str {
+        &self.bucket
+    }
     pub fn get_request<'a, S>(&self, object: S) -> SignedRequest
     where
         S: Into<Option<&'a str>>,
@@ -68,6 +71,26 @@ impl<C: SignAndDispatch + Send + Sync> OSSClient<C> {
     {
         self.generate_request("PUT", object, payload)
     }
+    /// Content-Length is taken from the stream's size_hint. Without one the body is sent
+    /// with chunked transfer encoding.
+    pub fn put_stream_request<S>(&self, object: S, stream: ByteStream) -> SignedRequest
+    where
+        S: Into<String>,
+    {
+        let mut signed_rqst = self.generate_request("PUT", object, None);
+        match signed_rqst.load_stream(stream) {
+            Some(_len) => signed_rqst.add_header("content-length", _len.to_string()),
+            None => signed_rqst.remove_header("content-length"),
+        }
+        signed_rqst
+    }
+    pub fn post_request<S, P>(&self, object: S, payload: P) -> SignedRequest
+    where
+        S: Into<String>,
+        P: Into<Option<Box<[u8]>>>,
+    {
+        self.generate_request("POST", object, payload)
+    }
     pub fn head_request<S>(&self, object: S) -> SignedRequest
     where
         S: Into<String>,
@@ -86,6 +109,12 @@ impl<C: SignAndDispatch + Send + Sync> OSSClient<C> {
     ) -> Result<HttpResponse, OSSError> {
         self.client.sign_and_dispatch(request).await
     }
+    pub async fn sign_and_dispatch_streaming(
+        &self,
+        request: SignedRequest,
+    ) -> Result<StreamingHttpResponse, OSSError> {
+        self.client.sign_and_dispatch_streaming(request).await
+    }
     pub fn get_signed_url<'a, H>(
         &self,
         object: &str,
@@ -199,8 +228,8 @@ mod tests {
     const FILE_NAME: &str = "rust_oss_sdk_test";
     const BUF: &[u8] = "This is just a put test".as_bytes();
 
-    #[test]
-    fn smoke_test() {
+    #[tokio::test]
+    async fn smoke_test() {
         let bucket = std::env::var("OSS_BUCKET").unwrap();
         let access_key_id = std::env::var("OSS_KEY_ID").unwrap();
         let access_key_secret = std::env::var("OSS_KEY_SECRET").unwrap();
@@ -215,28 +244,28 @@ mod tests {
 
         let mut rqst = oss_instance.put_request(FILE_NAME, BUF.to_vec().into_boxed_slice());
         rqst.add_meta([("test-key", "test-val")].iter().map(|a| a.to_owned()));
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().status.is_success());
 
         let mut rqst = oss_instance.get_request(None);
         rqst.add_params("prefix", "rust_oss_sdk");
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().status.is_success());
 
         let rqst = oss_instance.get_request(FILE_NAME);
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().body == Box::pin(BUF));
 
         let rqst = oss_instance.head_request(FILE_NAME);
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().headers.contains_key("x-oss-meta-test-key"));
 
         let rqst = oss_instance.del_request(FILE_NAME);
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().status.is_success());
 
         let rqst = oss_instance.get_request(FILE_NAME);
-        let ret = oss_instance.sign_and_dispatch(rqst);
+        let ret = oss_instance.sign_and_dispatch(rqst).await;
         assert!(ret.is_ok() && ret.unwrap().status.is_client_error());
     }
 }
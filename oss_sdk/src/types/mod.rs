@@ -1,5 +1,7 @@
 mod regions;
 mod schema;
+mod stream;
 
 pub use regions::*;
-pub use schema::*;
\ No newline at end of file
+pub use schema::*;
+pub use stream::*;
\ No newline at end of file
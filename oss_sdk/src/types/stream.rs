@@ -6,7 +6,7 @@ use std::task::{Context, Poll};
 use bytes::{BufMut, Bytes, BytesMut};
 use futures::{future, stream, Stream, StreamExt};
 use pin_project_lite::pin_project;
-use tokio::io::{AsyncRead, ReadBuf};
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
 
 pin_project! {
     /// Stream of bytes.
@@ -17,6 +17,9 @@ pin_project! {
     }
 }
 
+/// Chunk size used when reading from an `AsyncRead`.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
 impl ByteStream {
     /// Create a new `ByteStream` by wrapping a `futures` stream.
     pub fn new<S>(stream: S) -> ByteStream
@@ -41,6 +44,23 @@ impl ByteStream {
         }
     }
 
+    /// Create a new `ByteStream` reading chunks from an `AsyncRead`.
+    pub fn from_async_read<R>(reader: R) -> ByteStream
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        ByteStream::new(read_chunks(reader))
+    }
+
+    /// Create a new `ByteStream` reading chunks from an `AsyncRead`, with the size_hint
+    /// used as Content-Length on upload.
+    pub fn from_async_read_with_size<R>(reader: R, size_hint: usize) -> ByteStream
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        ByteStream::new_with_size(read_chunks(reader), size_hint)
+    }
+
     pub fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
@@ -56,6 +76,22 @@ impl ByteStream {
     }
 }
 
+fn read_chunks<R>(reader: R) -> impl Stream<Item = Result<Bytes, io::Error>> + Send
+where
+    R: AsyncRead + Send + 'static,
+{
+    // The reader is dropped after the first error, which ends the stream.
+    stream::unfold(Some(Box::pin(reader)), |reader| async move {
+        let mut reader = reader?;
+        let mut buf = BytesMut::with_capacity(READ_CHUNK_SIZE);
+        match reader.read_buf(&mut buf).await {
+            Ok(0) => None,
+            Ok(_) => Some((Ok(buf.freeze()), Some(reader))),
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
 impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
         ByteStream {
@@ -150,77 +186,99 @@ impl io::Read for ImplBlockingRead {
     }
 }
 
-#[tokio::test]
-async fn test_async_read() {
-    use bytes::Bytes;
-    use tokio::io::AsyncReadExt;
-
-    let chunks = vec![
-        Ok(Bytes::from_static(b"1234")),
-        Ok(Bytes::from_static(b"5678")),
-    ];
-    let stream = ByteStream::new(stream::iter(chunks));
-    let mut async_read = stream.into_async_read();
-
-    let mut buf = [0u8; 3];
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
-    assert_eq!(&buf[..3], b"123");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
-    assert_eq!(&buf[..1], b"4");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
-    assert_eq!(&buf[..3], b"567");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
-    assert_eq!(&buf[..1], b"8");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-#[test]
-fn test_blocking_read() {
-    use bytes::Bytes;
-    use std::io::Read;
-
-    let chunks = vec![
-        Ok(Bytes::from_static(b"1234")),
-        Ok(Bytes::from_static(b"5678")),
-    ];
-    let stream = ByteStream::new(stream::iter(chunks));
-    let mut async_read = stream.into_blocking_read();
-
-    let mut buf = [0u8; 3];
-    assert_eq!(async_read.read(&mut buf).unwrap(), 3);
-    assert_eq!(&buf[..3], b"123");
-    assert_eq!(async_read.read(&mut buf).unwrap(), 1);
-    assert_eq!(&buf[..1], b"4");
-    assert_eq!(async_read.read(&mut buf).unwrap(), 3);
-    assert_eq!(&buf[..3], b"567");
-    assert_eq!(async_read.read(&mut buf).unwrap(), 1);
-    assert_eq!(&buf[..1], b"8");
-    assert_eq!(async_read.read(&mut buf).unwrap(), 0);
-}
+    #[tokio::test]
+    async fn test_async_read() {
+        use bytes::Bytes;
+        use tokio::io::AsyncReadExt;
 
-#[tokio::test]
-async fn test_new_with_size_read() {
-    use bytes::Bytes;
-    use tokio::io::AsyncReadExt;
-
-    let chunks = vec![
-        Ok(Bytes::from_static(b"1234")),
-        Ok(Bytes::from_static(b"5678")),
-    ];
-    let stream = ByteStream::new_with_size(stream::iter(chunks), 8);
-
-    assert_eq!(stream.size_hint, Some(8));
-
-    let mut async_read = stream.into_async_read();
-
-    let mut buf = [0u8; 3];
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
-    assert_eq!(&buf[..3], b"123");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
-    assert_eq!(&buf[..1], b"4");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
-    assert_eq!(&buf[..3], b"567");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
-    assert_eq!(&buf[..1], b"8");
-    assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
+        let chunks = vec![
+            Ok(Bytes::from_static(b"1234")),
+            Ok(Bytes::from_static(b"5678")),
+        ];
+        let stream = ByteStream::new(stream::iter(chunks));
+        let mut async_read = stream.into_async_read();
+
+        let mut buf = [0u8; 3];
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
+        assert_eq!(&buf[..3], b"123");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
+        assert_eq!(&buf[..1], b"4");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
+        assert_eq!(&buf[..3], b"567");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
+        assert_eq!(&buf[..1], b"8");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_blocking_read() {
+        use bytes::Bytes;
+        use std::io::Read;
+
+        let chunks = vec![
+            Ok(Bytes::from_static(b"1234")),
+            Ok(Bytes::from_static(b"5678")),
+        ];
+        let stream = ByteStream::new(stream::iter(chunks));
+        let mut async_read = stream.into_blocking_read();
+
+        let mut buf = [0u8; 3];
+        assert_eq!(async_read.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], b"123");
+        assert_eq!(async_read.read(&mut buf).unwrap(), 1);
+        assert_eq!(&buf[..1], b"4");
+        assert_eq!(async_read.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], b"567");
+        assert_eq!(async_read.read(&mut buf).unwrap(), 1);
+        assert_eq!(&buf[..1], b"8");
+        assert_eq!(async_read.read(&mut buf).unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_new_with_size_read() {
+        use bytes::Bytes;
+        use tokio::io::AsyncReadExt;
+
+        let chunks = vec![
+            Ok(Bytes::from_static(b"1234")),
+            Ok(Bytes::from_static(b"5678")),
+        ];
+        let stream = ByteStream::new_with_size(stream::iter(chunks), 8);
+
+        assert_eq!(stream.size_hint, Some(8));
+
+        let mut async_read = stream.into_async_read();
+
+        let mut buf = [0u8; 3];
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
+        assert_eq!(&buf[..3], b"123");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
+        assert_eq!(&buf[..1], b"4");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 3);
+        assert_eq!(&buf[..3], b"567");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 1);
+        assert_eq!(&buf[..1], b"8");
+        assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_from_async_read() {
+        use tokio::io::AsyncReadExt;
+
+        let content = vec![7u8; READ_CHUNK_SIZE + 8];
+        let stream = ByteStream::from_async_read_with_size(
+            std::io::Cursor::new(content.clone()),
+            content.len(),
+        );
+
+        assert_eq!(stream.size_hint(), Some(content.len()));
+
+        let mut buf = Vec::new();
+        stream.into_async_read().read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, content);
+    }
 }
@@ -3,7 +3,189 @@ use crate::{aws::S3Client, inner_client::InnerClient};
 use super::*;
 // use crate::{aws::S3Client, inner_client::InnerClient};
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use std::collections::HashMap;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// list_stream 翻页的状态, 持有 ListOptions 中字符串的拷贝, 不受其生命周期限制。
+/// marker 在 V1 中为 marker, 在 V2 中为 continuation_token。
+struct ListPager {
+    prefix: Option<String>,
+    delimiter: Option<String>,
+    marker: Option<String>,
+    max_keys: Option<usize>,
+    remaining: Option<usize>,
+    v2: bool,
+    start_after: Option<String>,
+    fetch_owner: Option<bool>,
+    is_done: bool,
+}
+
+impl ListPager {
+    fn new(opts: ListOptions) -> Self {
+        let marker = if opts.v2 {
+            opts.continuation_token
+        } else {
+            opts.marker
+        };
+        Self {
+            prefix: opts.prefix.map(|_prefix| _prefix.to_owned()),
+            delimiter: opts.delimiter.map(|_delimiter| _delimiter.to_owned()),
+            marker: marker.map(|_marker| _marker.to_owned()),
+            max_keys: opts.max_keys,
+            remaining: opts.limit,
+            v2: opts.v2,
+            start_after: opts.start_after.map(|_start_after| _start_after.to_owned()),
+            fetch_owner: opts.fetch_owner,
+            is_done: false,
+        }
+    }
+
+    /// 下一页的 ListOptions, 有 limit 时不会多取。
+    fn next_opts(&self) -> ListOptions<'_> {
+        let max_keys = match (self.max_keys, self.remaining) {
+            (Some(_max_keys), Some(_remaining)) => Some(_max_keys.min(_remaining)),
+            (_max_keys, _remaining) => _max_keys.or(_remaining),
+        };
+        if self.v2 {
+            ListOptions {
+                fetch_owner: self.fetch_owner,
+                ..ListOptions::new_v2(
+                    self.prefix.as_deref(),
+                    self.marker.as_deref(),
+                    self.start_after.as_deref(),
+                    self.delimiter.as_deref(),
+                    max_keys,
+                )
+            }
+        } else {
+            ListOptions::new(
+                self.prefix.as_deref(),
+                self.marker.as_deref(),
+                self.delimiter.as_deref(),
+                max_keys,
+            )
+        }
+    }
+
+    /// 根据本页的结果更新 marker, 返回本页的 Objects 和 CommonPrefixes。
+    fn advance(&mut self, resp: ListDetailsResp) -> (Vec<ObjectDetails>, Vec<String>) {
+        let mut objects = resp.objects;
+        if let Some(_remaining) = self.remaining.as_mut() {
+            objects.truncate(*_remaining);
+            *_remaining -= objects.len();
+        }
+        self.marker = if self.v2 {
+            Some(resp.next_continuation_token).filter(|_token| !_token.is_empty())
+        } else if resp.next_marker.is_empty() {
+            // S3 在没有 delimiter 时不返回 NextMarker, 以本页最后一个 Key 作为 marker。
+            let last_key = objects.last().map(|_obj| _obj.key.as_str());
+            let last_prefix = resp.common_prefixes.last().map(|_prefix| _prefix.as_str());
+            last_key.max(last_prefix).map(|_marker| _marker.to_owned())
+        } else {
+            Some(resp.next_marker)
+        };
+        self.is_done = !resp.is_truncated || self.marker.is_none() || self.remaining == Some(0);
+        (objects, resp.common_prefixes)
+    }
+}
+
+/// copy_large 中 initiate_multipart 的参数。MetadataDirective 为 Copy 时元数据取自源 Object,
+/// 未指定的存储类型和标签也沿用源 Object, 写入条件、加密和 ACL 仍取自 opts。
+fn copy_init_opts<'a>(
+    opts: PutOrCopyOptions<'a>,
+    src_meta: &'a ObjectMeta,
+    src_tags: Option<HashMap<String, String>>,
+) -> PutOrCopyOptions<'a> {
+    match opts.metadata_directive() {
+        MetadataDirective::Copy => PutOrCopyOptions {
+            meta: Some(src_meta.user_meta.clone()),
+            content_type: src_meta.content_type.as_deref(),
+            cache_control: src_meta.cache_control.as_deref(),
+            content_disposition: src_meta.content_disposition.as_deref(),
+            content_encoding: src_meta.content_encoding.as_deref(),
+            if_match: opts.if_match,
+            if_none_match: opts.if_none_match,
+            forbid_overwrite: opts.forbid_overwrite,
+            encryption: opts.encryption,
+            storage_class: opts
+                .storage_class
+                .or_else(|| src_meta.storage_class.parse().ok()),
+            acl: opts.acl,
+            tags: opts.tags.or(src_tags),
+            ..Default::default()
+        },
+        MetadataDirective::Replace => opts,
+    }
+}
+
+/// copy_large 的通用部分, src_meta 为源 Object 的元数据, 写入条件由调用方事先检查。
+pub(crate) async fn copy_multipart<A>(
+    client: &A,
+    src: &str,
+    key: &str,
+    src_meta: ObjectMeta,
+    part_size: Option<usize>,
+    opts: PutOrCopyOptions<'_>,
+) -> Result<CopyResp>
+where
+    A: AwosApi + Sync,
+{
+    let size = src_meta.content_length;
+    let part_size = part_size.unwrap_or(DEFAULT_COPY_PART_SIZE);
+    types::check_part_size(part_size)?;
+    let part_size = part_size as u64;
+    if size <= part_size {
+        return client.copy(src, key, opts).await;
+    }
+    let part_size = part_size.max(size.div_ceil(types::MAX_PARTS));
+    let (src_bucket, src_version_id) = (opts.src_bucket, opts.src_version_id);
+    // 与单次 copy 一致, 未指定 tags 时沿用源 Object 的标签。
+    // get_tagging 只能读取当前 Bucket 中 Object 的最新版本, 跨 Bucket 或指定版本时不沿用标签。
+    let src_tags = match opts.metadata_directive() {
+        MetadataDirective::Copy
+            if opts.tags.is_none() && src_bucket.is_none() && src_version_id.is_none() =>
+        {
+            Some(client.get_tagging(src).await?).filter(|_tags| !_tags.is_empty())
+        }
+        _ => None,
+    };
+    let init_opts = copy_init_opts(opts, &src_meta, src_tags);
+    let upload_id = client.initiate_multipart(key, init_opts).await?;
+    let upload = async {
+        let parts = stream::iter((0..size).step_by(part_size as usize).enumerate())
+            .map(|(idx, start)| {
+                let upload_id = &upload_id;
+                async move {
+                    let part_number = idx + 1;
+                    let range = ByteRange::Range(start, (start + part_size).min(size) - 1);
+                    let opts = PutOrCopyOptions {
+                        src_bucket,
+                        src_version_id,
+                        ..Default::default()
+                    };
+                    let e_tag = client
+                        .upload_part_copy(key, upload_id, part_number, src, range, opts)
+                        .await?;
+                    Ok::<_, Error>(CompletedPart { part_number, e_tag })
+                }
+            })
+            .buffered(types::COPY_LARGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        client.complete_multipart(key, &upload_id, parts).await
+    };
+    match upload.await {
+        Ok(resp) => Ok(CopyResp {
+            e_tag: types::trim_etag(&resp.e_tag).to_owned(),
+            version_id: resp.version_id,
+        }),
+        Err(e) => {
+            client.abort_multipart(key, &upload_id).await.ok();
+            Err(e)
+        }
+    }
+}
 
 #[async_trait]
 pub trait AwosApi {
@@ -19,47 +201,389 @@ pub trait AwosApi {
     where
         O: Into<Option<ListOptions<'a>>> + Send;
 
+    /// 以 Stream 的形式获取当前 Bucket 下所有 Objects 的信息, 按需自动翻页。
+    /// max_keys 作为每页的大小, limit 为合计返回的最大数量。出错时 Stream 返回该错误后结束。
+    fn list_stream<'a, 'b, O>(&'a self, opts: O) -> BoxStream<'a, Result<ObjectDetails>>
+    where
+        Self: Sync,
+        O: Into<Option<ListOptions<'b>>>,
+    {
+        let pager = ListPager::new(opts.into().unwrap_or_default());
+        stream::try_unfold(pager, move |mut pager| async move {
+            if pager.is_done || pager.remaining == Some(0) {
+                return Ok(None);
+            }
+            let resp = self.list_details(pager.next_opts()).await?;
+            let (objects, _) = pager.advance(resp);
+            Ok::<_, Error>(Some((stream::iter(objects.into_iter().map(Ok)), pager)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    /// 获取当前 Bucket 下所有 Objects 的信息, 会自动翻页直到结束或达到 limit。
+    /// 可选参数同 list_stream。
+    async fn list_all<'a, O>(&self, opts: O) -> Result<Vec<ObjectDetails>>
+    where
+        O: Into<Option<ListOptions<'a>>> + Send,
+    {
+        self.list_stream(opts).try_collect().await
+    }
+
+    /// 像文件系统一样列出一层 "目录": 以 "/" 为 delimiter, 返回 dir 下的 Objects,
+    /// 以及 common_prefixes 中的子目录, 会自动翻页。
+    /// dir 为空时列出根目录, 不以 "/" 结尾时会自动补上。
+    async fn list_dir<S>(&self, dir: S) -> Result<ListDetailsResp>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut prefix = dir.as_ref().to_owned();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+        let mut pager = ListPager::new(ListOptions::new(prefix.as_str(), None, "/", None));
+        let mut result = ListDetailsResp {
+            prefix: prefix.to_owned(),
+            ..Default::default()
+        };
+        while !pager.is_done {
+            let resp = self.list_details(pager.next_opts()).await?;
+            let (mut objects, mut common_prefixes) = pager.advance(resp);
+            result.objects.append(&mut objects);
+            result.common_prefixes.append(&mut common_prefixes);
+        }
+        Ok(result)
+    }
+
+    /// 列出 Object 的所有版本和删除标记, Bucket 未开启版本控制时 version_id 均为 "null"。
+    /// 可选参数详见 ListVersionsOptions, 被截断时用返回的 next_key_marker/next_version_id_marker 请求下一页。
+    async fn list_versions<'a, O>(&self, opts: O) -> Result<ListVersionsResp>
+    where
+        O: Into<Option<ListVersionsOptions<'a>>> + Send;
+
     /// Get 一个 Object
     /// 可选参数是一个 Metas 的过滤器， 仅在此中指定的 Metas 才会被返回。
-    async fn get<'a, S, M, F>(&self, key: S, meta_keys_filter: M) -> Result<types::GetResp>
+    /// opts 详见 GetOptions, 可以通过 range 只读取 Object 的一部分。
+    async fn get<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<types::GetResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
-        F: IntoIterator<Item = &'a str> + Send;
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send;
     /// Get 一个 Object, Content 为在buffer中的二进制数据。
     /// 可选参数是一个 Metas 的过滤器，传入 None 时不过滤， 传入其他集合类型时，仅返集合中指定的 Metas。
     ///
     /// TODO: 因为这里用了泛型， 传入 None 的时候无法推断出 F 的类型， 只能通过 ::<> 传入一个类型变量。
     ///       不是很好用，需要找个方法改进一下。
-
-    async fn get_as_buffer<'a, S, M, F>(
+    /// 指定了 range 时返回的 total_size 为整个 Object 的大小, 而不是 content 的长度。
+    async fn get_as_buffer<'a, S, M, F, O>(
         &self,
         key: S,
         meta_keys_filter: M,
+        opts: O,
     ) -> Result<GetAsBufferResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
-        F: IntoIterator<Item = &'a str> + Send;
-    async fn head<S>(&self, key: S) -> Result<HashMap<String, String>>
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send;
+    /// Get 一个 Object, Content 为 ByteStream, 不会将整个 Object 读入内存, 适合大文件的转发。
+    /// 可选参数同 get_as_buffer。
+    async fn get_stream<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<GetStreamResp>
     where
-        S: AsRef<str> + Send;
-    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<()>
+        S: AsRef<str> + Send,
+        M: Into<Option<F>> + Send,
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send;
+    /// 获取 Object 的元信息, 不读取内容。详见 ObjectMeta。
+    /// version_id 为 None 时获取最新版本。
+    async fn head<'a, S, V>(&self, key: S, version_id: V) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send;
+    /// 轻量的 head, OSS 使用 GetObjectMeta (?objectMeta), 只返回 content_length, e_tag 和 last_modified。
+    /// S3 没有对应的接口, 等同于 head。
+    async fn get_object_meta<S>(&self, key: S) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.head(key, None).await
+    }
+    /// 上传一个 Object, 可选参数详见 PutOrCopyOptions。
+    /// 设置了 if_match/if_none_match/forbid_overwrite 且条件不满足时返回 Error::PreconditionFailed,
+    /// 条件由服务端在写入时判断。
+    /// 返回的 PutResp 中包含 ETag, 以及开启了版本控制时新版本的 version_id。
+    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<PutResp>
     where
         S: AsRef<str> + Send,
         D: Into<Box<[u8]>> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send;
-    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<()>
+    /// 以 ByteStream 上传 Object, 数据边读边发送, 不会整个读入内存。
+    /// size_hint 会作为 Content-Length, 可以用 ByteStream::from_async_read_with_size 从 AsyncRead 构建。
+    /// S3 不支持未知长度的上传, 没有 size_hint 时会转为 put_multipart, 此时不支持写入条件。
+    async fn put_stream<'a, S, O>(&self, key: S, body: ByteStream, opts: O) -> Result<PutResp>
     where
-        S1: Into<String> + Send,
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 追加写入 Appendable 类型的 Object, 仅 OSS 支持, S3 返回 Error::Unsupported。
+    /// position 为本次写入的起始位置, 首次写入时为 0, 之后为上次返回的 next_position。
+    /// position 与 Object 当前长度不一致时返回 Error::PreconditionFailed。
+    /// opts 中的 meta/Header 等仅在首次写入时生效。
+    async fn append<'a, S, D, O>(
+        &self,
+        key: S,
+        position: u64,
+        data: D,
+        opts: O,
+    ) -> Result<AppendResp>
+    where
+        S: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 创建指向 target 的软链接, 仅 OSS 支持, S3 返回 Error::Unsupported。
+    /// 不检查 target 是否存在, 可选参数中 meta/storage_class/acl/forbid_overwrite 等生效。
+    async fn put_symlink<'a, S1, S2, O>(&self, key: S1, target: S2, opts: O) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send;
-    async fn del<S>(&self, key: S) -> Result<()>
+    /// 获取软链接指向的 target, 仅 OSS 支持, S3 返回 Error::Unsupported。
+    async fn get_symlink<S>(&self, key: S) -> Result<String>
     where
         S: AsRef<str> + Send;
-    async fn del_multi<S>(&self, keys: &[S]) -> Result<()>
+    /// 复制 Object, 返回目标 Object 的 ETag。写入条件同 put, 针对的是目标 key, S3 不支持, 返回 Error::Unsupported。
+    /// src 为源 Object 的 key, 源 Bucket/版本和元数据的处理方式见 PutOrCopyOptions。
+    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 通过 Multipart Copy 复制 Object, 用于超过 5 GB 的 Object, 数据不经过本机。
+    /// 按 part_size (默认 DEFAULT_COPY_PART_SIZE) 切分并发调用 upload_part_copy, 源 Object 不大于 part_size 时直接 copy。
+    /// 可选参数同 copy, MetadataDirective 为 Copy 时会先读取源 Object 的元数据、存储类型和标签,
+    /// 源 Object 在其他 Bucket 或指定了 src_version_id 时不会复制标签。
+    /// part_size 小于 MIN_PART_SIZE, 或使用了 SSE-C 时返回 Error::Unsupported。任一 Part 失败时会 abort 本次 Upload, 并返回该错误。
+    async fn copy_large<'a, S1, S2, N, O>(
+        &self,
+        src: S1,
+        key: S2,
+        part_size: N,
+        opts: O,
+    ) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 删除 Object, version_id 为 None 时在开启了版本控制的 Bucket 中只会生成删除标记,
+    /// 指定 version_id 时永久删除该版本 (包括删除标记)。
+    async fn del<'a, S, V>(&self, key: S, version_id: V) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send;
+    /// 批量删除 Object, 超过 1000 个 Key 时会拆分为多次请求。
+    /// 单个 Key 删除失败不会返回 Err, 而是记录在 DeleteMultiResp.errors 中。
+    /// quiet 为 true 时服务端只返回失败的 Key, deleted 为空。
+    async fn del_multi<S>(&self, keys: &[S], quiet: bool) -> Result<DeleteMultiResp>
     where
         S: AsRef<str> + Sync;
+    /// 解冻归档类型的 Object, 解冻是异步的, 可以通过 head 返回的 restore 查看进度。
+    /// 对已解冻完成的 Object 再次调用会延长解冻副本的保留时间。
+    /// 对非归档类型的 Object 调用, 或解冻正在进行中时返回 Error::PreconditionFailed。
+    async fn restore<S, O>(&self, key: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<RestoreOptions>> + Send;
+
+    /// 获取 Object 的标签, 没有标签时返回空的 HashMap。
+    async fn get_tagging<S>(&self, key: S) -> Result<HashMap<String, String>>
+    where
+        S: AsRef<str> + Send;
+    /// 设置 Object 的标签, 会覆盖已有的全部标签。
+    async fn put_tagging<S>(&self, key: S, tags: HashMap<String, String>) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+    /// 删除 Object 的全部标签。
+    async fn delete_tagging<S>(&self, key: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// 获取 Object 的访问权限。S3 根据 Grants 推断, 不匹配任何 Canned ACL 时返回 Private。
+    async fn get_object_acl<S>(&self, key: S) -> Result<Acl>
+    where
+        S: AsRef<str> + Send;
+    /// 设置 Object 的访问权限。S3 不支持 Acl::Default, 此时返回 Error::Unsupported。
+    async fn put_object_acl<S>(&self, key: S, acl: Acl) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+    /// 获取 Bucket 的访问权限。
+    async fn get_bucket_acl(&self) -> Result<Acl>;
+    /// 设置 Bucket 的访问权限, Bucket 不支持 Acl::Default, 此时返回 Error::Unsupported。
+    async fn put_bucket_acl(&self, acl: Acl) -> Result<()>;
+
+    /// 以下为 Bucket 级别的操作, 参数 bucket 不受 Client 绑定的 Bucket 限制。
+    /// 创建 Bucket, 可选参数详见 CreateBucketOptions。Bucket 已存在时返回 Error::PreconditionFailed。
+    async fn create_bucket<'a, S, O>(&self, bucket: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<CreateBucketOptions<'a>>> + Send;
+    /// 删除 Bucket, Bucket 中还有 Object 或未完成的 Multipart Upload 时会失败。
+    async fn delete_bucket<S>(&self, bucket: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+    /// 列出当前账号下名称以 prefix 开头的 Bucket, 会自动翻页。
+    async fn list_buckets<'a, P>(&self, prefix: P) -> Result<Vec<BucketInfo>>
+    where
+        P: Into<Option<&'a str>> + Send;
+    /// Bucket 是否存在, 存在但没有访问权限时返回 Err。
+    async fn bucket_exists<S>(&self, bucket: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send;
+    /// 获取 Bucket 的信息, 详见 BucketInfo。S3 没有对应的接口, 由 GetBucketLocation/GetBucketAcl/ListBuckets 组合而成。
+    async fn get_bucket_info<S>(&self, bucket: S) -> Result<BucketInfo>
+    where
+        S: AsRef<str> + Send;
+    /// 获取 Bucket 所在的地域, 如 oss-cn-beijing/us-east-1。
+    async fn get_bucket_location<S>(&self, bucket: S) -> Result<String>
+    where
+        S: AsRef<str> + Send;
+
+    /// 获取当前 Bucket 的生命周期规则, 没有设置过时返回空。
+    async fn get_bucket_lifecycle(&self) -> Result<Vec<LifecycleRule>>;
+    /// 设置当前 Bucket 的生命周期规则, 会整体替换已有的规则。
+    async fn put_bucket_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()>;
+    /// 删除当前 Bucket 的所有生命周期规则。
+    async fn delete_bucket_lifecycle(&self) -> Result<()>;
+
+    /// 获取当前 Bucket 的跨域规则 (CORS), 没有设置过时返回空。
+    async fn get_bucket_cors(&self) -> Result<Vec<CorsRule>>;
+    /// 设置当前 Bucket 的跨域规则, 会整体替换已有的规则。
+    async fn put_bucket_cors(&self, rules: Vec<CorsRule>) -> Result<()>;
+    /// 删除当前 Bucket 的所有跨域规则。
+    async fn delete_bucket_cors(&self) -> Result<()>;
+
+    /// 初始化一个 Multipart Upload, 返回 Upload ID。
+    /// 可选参数与 put 一致, 作用于最终合成的 Object。不支持 SSE-C, 此时返回 Error::Unsupported。
+    async fn initiate_multipart<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 上传一个 Part, 返回该 Part 的 ETag。
+    /// part_number 取值 1 ~ 10000, 重复上传同一编号会覆盖之前的数据。
+    async fn upload_part<S1, S2, D>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        data: D,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send;
+    /// 复制源 Object 中 range 范围的数据作为一个 Part, 返回该 Part 的 ETag。
+    /// range 为 None 时复制整个源 Object, 仅支持 ByteRange::Range。
+    /// 源 Bucket/版本取自 opts 的 src_bucket/src_version_id, 其余参数不生效。
+    async fn upload_part_copy<'a, S1, S2, S3, R, O>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        src: S3,
+        range: R,
+        opts: O,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: Into<String> + Send,
+        R: Into<Option<ByteRange>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send;
+    /// 用已上传的 Parts 合成 Object。parts 需按 part_number 升序排列。
+    async fn complete_multipart<S1, S2>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+    /// 取消 Multipart Upload, 已上传的 Parts 会被删除。
+    async fn abort_multipart<S1, S2>(&self, key: S1, upload_id: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// 从 source 中按 part_size (默认 DEFAULT_PART_SIZE) 依次读取并上传 Parts, 内存中最多只保留一个 Part。
+    /// part_size 小于 MIN_PART_SIZE 时返回 Error::Unsupported, source 超过 MAX_PARTS 个 Part 时同样返回该错误。
+    /// 任一步骤失败时会 abort 本次 Upload, 并返回该错误。
+    async fn put_multipart<'a, S, R, N, O>(
+        &self,
+        key: S,
+        mut source: R,
+        part_size: N,
+        opts: O,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S: AsRef<str> + Send,
+        R: AsyncRead + Unpin + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let key = key.as_ref();
+        let part_size = part_size.into().unwrap_or(DEFAULT_PART_SIZE);
+        types::check_part_size(part_size)?;
+        let upload_id = self.initiate_multipart(key, opts).await?;
+        let upload = async {
+            let mut parts = Vec::new();
+            loop {
+                let mut buf = Vec::with_capacity(part_size);
+                (&mut source)
+                    .take(part_size as u64)
+                    .read_to_end(&mut buf)
+                    .await?;
+                // 空的 source 也需要上传一个 Part
+                if buf.is_empty() && !parts.is_empty() {
+                    break;
+                }
+                let is_last = buf.len() < part_size;
+                let part_number = parts.len() + 1;
+                if part_number as u64 > types::MAX_PARTS {
+                    return Err(Error::Unsupported {
+                        msg: format!(
+                            "source exceeds {} parts of {} bytes",
+                            types::MAX_PARTS,
+                            part_size
+                        ),
+                    });
+                }
+                let e_tag = self.upload_part(key, &upload_id, part_number, buf).await?;
+                parts.push(CompletedPart { part_number, e_tag });
+                if is_last {
+                    break;
+                }
+            }
+            self.complete_multipart(key, &upload_id, parts).await
+        };
+        match upload.await {
+            Ok(resp) => Ok(resp),
+            Err(e) => {
+                self.abort_multipart(key, &upload_id).await.ok();
+                Err(e)
+            }
+        }
+    }
 
     fn sign_url<'a, S, O>(&self, key: S, opts: O) -> Result<String>
     where
@@ -109,7 +633,7 @@ impl AwosClient {
         })
     }
 
-    pub fn new_with_s3<'a, S1, S2, S3, S4>(
+    pub fn new_with_s3<S1, S2, S3, S4>(
         endpoint: S1,
         bucket: S2,
         access_key_id: S3,
@@ -147,36 +671,69 @@ impl AwosApi for AwosClient {
         self.inner.list_details(opts).await
     }
 
-    async fn get<'a, S, M, F>(&self, key: S, meta_keys_filter: M) -> Result<GetResp>
+    async fn list_versions<'a, O>(&self, opts: O) -> Result<ListVersionsResp>
+    where
+        O: Into<Option<ListVersionsOptions<'a>>> + Send,
+    {
+        self.inner.list_versions(opts).await
+    }
+
+    async fn get<'a, S, M, F, O>(&self, key: S, meta_keys_filter: M, opts: O) -> Result<GetResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        self.inner.get(key, meta_keys_filter).await
+        self.inner.get(key, meta_keys_filter, opts).await
     }
 
-    async fn get_as_buffer<'a, S, M, F>(
+    async fn get_as_buffer<'a, S, M, F, O>(
         &self,
         key: S,
         meta_keys_filter: M,
+        opts: O,
     ) -> Result<GetAsBufferResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        self.inner.get_as_buffer(key, meta_keys_filter).await
+        self.inner.get_as_buffer(key, meta_keys_filter, opts).await
     }
 
-    async fn head<S>(&self, key: S) -> Result<HashMap<String, String>>
+    async fn get_stream<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<GetStreamResp>
     where
         S: AsRef<str> + Send,
+        M: Into<Option<F>> + Send,
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        self.inner.head(key).await
+        self.inner.get_stream(key, meta_keys_filter, opts).await
     }
 
-    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<()>
+    async fn head<'a, S, V>(&self, key: S, version_id: V) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
+    {
+        self.inner.head(key, version_id).await
+    }
+
+    async fn get_object_meta<S>(&self, key: S) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_object_meta(key).await
+    }
+
+    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<PutResp>
     where
         S: AsRef<str> + Send,
         D: Into<Box<[u8]>> + Send,
@@ -185,7 +742,46 @@ impl AwosApi for AwosClient {
         self.inner.put(key, data, opts).await
     }
 
-    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<()>
+    async fn put_stream<'a, S, O>(&self, key: S, body: ByteStream, opts: O) -> Result<PutResp>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner.put_stream(key, body, opts).await
+    }
+
+    async fn append<'a, S, D, O>(
+        &self,
+        key: S,
+        position: u64,
+        data: D,
+        opts: O,
+    ) -> Result<AppendResp>
+    where
+        S: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner.append(key, position, data, opts).await
+    }
+
+    async fn put_symlink<'a, S1, S2, O>(&self, key: S1, target: S2, opts: O) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner.put_symlink(key, target, opts).await
+    }
+
+    async fn get_symlink<S>(&self, key: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_symlink(key).await
+    }
+
+    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<CopyResp>
     where
         S1: Into<String> + Send,
         S2: AsRef<str> + Send,
@@ -194,18 +790,220 @@ impl AwosApi for AwosClient {
         self.inner.copy(src, key, opts).await
     }
 
-    async fn del<S>(&self, key: S) -> Result<()>
+    async fn copy_large<'a, S1, S2, N, O>(
+        &self,
+        src: S1,
+        key: S2,
+        part_size: N,
+        opts: O,
+    ) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner.copy_large(src, key, part_size, opts).await
+    }
+
+    async fn del<'a, S, V>(&self, key: S, version_id: V) -> Result<()>
     where
         S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
-        self.inner.del(key).await
+        self.inner.del(key, version_id).await
     }
 
-    async fn del_multi<S>(&self, keys: &[S]) -> Result<()>
+    async fn del_multi<S>(&self, keys: &[S], quiet: bool) -> Result<DeleteMultiResp>
     where
         S: AsRef<str> + Sync,
     {
-        self.inner.del_multi(keys).await
+        self.inner.del_multi(keys, quiet).await
+    }
+
+    async fn restore<S, O>(&self, key: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<RestoreOptions>> + Send,
+    {
+        self.inner.restore(key, opts).await
+    }
+
+    async fn get_tagging<S>(&self, key: S) -> Result<HashMap<String, String>>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_tagging(key).await
+    }
+
+    async fn put_tagging<S>(&self, key: S, tags: HashMap<String, String>) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.put_tagging(key, tags).await
+    }
+
+    async fn delete_tagging<S>(&self, key: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.delete_tagging(key).await
+    }
+
+    async fn get_object_acl<S>(&self, key: S) -> Result<Acl>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_object_acl(key).await
+    }
+
+    async fn put_object_acl<S>(&self, key: S, acl: Acl) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.put_object_acl(key, acl).await
+    }
+
+    async fn get_bucket_acl(&self) -> Result<Acl> {
+        self.inner.get_bucket_acl().await
+    }
+
+    async fn put_bucket_acl(&self, acl: Acl) -> Result<()> {
+        self.inner.put_bucket_acl(acl).await
+    }
+
+    async fn create_bucket<'a, S, O>(&self, bucket: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<CreateBucketOptions<'a>>> + Send,
+    {
+        self.inner.create_bucket(bucket, opts).await
+    }
+
+    async fn delete_bucket<S>(&self, bucket: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.delete_bucket(bucket).await
+    }
+
+    async fn list_buckets<'a, P>(&self, prefix: P) -> Result<Vec<BucketInfo>>
+    where
+        P: Into<Option<&'a str>> + Send,
+    {
+        self.inner.list_buckets(prefix).await
+    }
+
+    async fn bucket_exists<S>(&self, bucket: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.bucket_exists(bucket).await
+    }
+
+    async fn get_bucket_info<S>(&self, bucket: S) -> Result<BucketInfo>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_bucket_info(bucket).await
+    }
+
+    async fn get_bucket_location<S>(&self, bucket: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_bucket_location(bucket).await
+    }
+
+    async fn get_bucket_lifecycle(&self) -> Result<Vec<LifecycleRule>> {
+        self.inner.get_bucket_lifecycle().await
+    }
+
+    async fn put_bucket_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        self.inner.put_bucket_lifecycle(rules).await
+    }
+
+    async fn delete_bucket_lifecycle(&self) -> Result<()> {
+        self.inner.delete_bucket_lifecycle().await
+    }
+
+    async fn get_bucket_cors(&self) -> Result<Vec<CorsRule>> {
+        self.inner.get_bucket_cors().await
+    }
+
+    async fn put_bucket_cors(&self, rules: Vec<CorsRule>) -> Result<()> {
+        self.inner.put_bucket_cors(rules).await
+    }
+
+    async fn delete_bucket_cors(&self) -> Result<()> {
+        self.inner.delete_bucket_cors().await
+    }
+
+    async fn initiate_multipart<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner.initiate_multipart(key, opts).await
+    }
+
+    async fn upload_part<S1, S2, D>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        data: D,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+    {
+        self.inner
+            .upload_part(key, upload_id, part_number, data)
+            .await
+    }
+
+    async fn upload_part_copy<'a, S1, S2, S3, R, O>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        src: S3,
+        range: R,
+        opts: O,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: Into<String> + Send,
+        R: Into<Option<ByteRange>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        self.inner
+            .upload_part_copy(key, upload_id, part_number, src, range, opts)
+            .await
+    }
+
+    async fn complete_multipart<S1, S2>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        self.inner.complete_multipart(key, upload_id, parts).await
+    }
+
+    async fn abort_multipart<S1, S2>(&self, key: S1, upload_id: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        self.inner.abort_multipart(key, upload_id).await
     }
 
     fn sign_url<'a, S, O>(&self, key: S, opts: O) -> Result<String>
@@ -216,3 +1014,101 @@ impl AwosApi for AwosClient {
         self.inner.sign_url(key, opts)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(keys: &[&str], is_truncated: bool) -> ListDetailsResp {
+        ListDetailsResp {
+            objects: keys
+                .iter()
+                .map(|_key| ObjectDetails {
+                    key: _key.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            is_truncated,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn list_pager_test() {
+        // V1 没有 NextMarker 时以最后一个 Key 翻页, limit 限制每页的 max_keys
+        let opts = ListOptions {
+            limit: Some(3),
+            ..ListOptions::new("p", None, None, 2)
+        };
+        let mut pager = ListPager::new(opts);
+        let next_opts = pager.next_opts();
+        assert_eq!(next_opts.max_keys, Some(2));
+        assert_eq!(next_opts.marker, None);
+        let (objects, _) = pager.advance(page(&["p/a", "p/b"], true));
+        assert_eq!(objects.len(), 2);
+        assert!(!pager.is_done);
+        let next_opts = pager.next_opts();
+        assert_eq!(next_opts.max_keys, Some(1));
+        assert_eq!(next_opts.marker, Some("p/b"));
+        assert_eq!(next_opts.prefix, Some("p"));
+        let (objects, _) = pager.advance(page(&["p/c", "p/d"], true));
+        assert_eq!(objects.len(), 1);
+        assert!(pager.is_done);
+
+        // V2 使用 continuation_token 翻页
+        let mut pager = ListPager::new(ListOptions::new_v2("p", None, "p/0", None, 1));
+        let next_opts = pager.next_opts();
+        assert!(next_opts.v2);
+        assert_eq!(next_opts.start_after, Some("p/0"));
+        let mut resp = page(&["p/a"], true);
+        resp.next_continuation_token = "token".to_owned();
+        pager.advance(resp);
+        assert_eq!(pager.next_opts().continuation_token, Some("token"));
+        pager.advance(page(&["p/b"], false));
+        assert!(pager.is_done);
+    }
+
+    #[test]
+    fn copy_init_opts_test() {
+        let src_meta = ObjectMeta {
+            content_type: Some("text/plain".to_owned()),
+            storage_class: "STANDARD_IA".to_owned(),
+            user_meta: vec![("k".to_owned(), "v".to_owned())].into_iter().collect(),
+            ..Default::default()
+        };
+        let src_tags = vec![("t".to_owned(), "1".to_owned())].into_iter().collect();
+        // Copy 时沿用源 Object 的元数据、存储类型和标签, 保留写入条件
+        let opts = PutOrCopyOptions {
+            if_none_match: Some("*"),
+            acl: Some(Acl::Private),
+            ..Default::default()
+        };
+        let init_opts = copy_init_opts(opts, &src_meta, Some(src_tags));
+        assert!(init_opts.forbids_overwrite());
+        assert_eq!(init_opts.content_type, Some("text/plain"));
+        assert_eq!(init_opts.storage_class, Some(StorageClass::IA));
+        assert_eq!(init_opts.acl, Some(Acl::Private));
+        assert_eq!(init_opts.meta.unwrap()["k"], "v");
+        assert_eq!(init_opts.tags.unwrap()["t"], "1");
+        // 显式指定的存储类型和标签优先
+        let opts = PutOrCopyOptions {
+            storage_class: Some(StorageClass::Archive),
+            tags: Some(HashMap::new()),
+            forbid_overwrite: Some(true),
+            metadata_directive: Some(MetadataDirective::Copy),
+            ..Default::default()
+        };
+        let init_opts = copy_init_opts(opts, &src_meta, None);
+        assert!(init_opts.forbids_overwrite());
+        assert_eq!(init_opts.storage_class, Some(StorageClass::Archive));
+        assert!(init_opts.tags.unwrap().is_empty());
+        // Replace 时原样使用 opts
+        let opts = PutOrCopyOptions {
+            content_type: Some("image/png"),
+            ..Default::default()
+        };
+        let init_opts = copy_init_opts(opts, &src_meta, None);
+        assert_eq!(init_opts.content_type, Some("image/png"));
+        assert!(init_opts.meta.is_none() && init_opts.storage_class.is_none());
+    }
+}
@@ -1,19 +1,67 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    pin::Pin,
+    sync::{Mutex, PoisonError},
+    task::{Context, Poll},
+};
 
 use async_trait::async_trait;
-use rusoto_core::HttpClient;
+use bytes::Bytes;
+use futures::Stream;
+use oss_sdk::ByteStream;
+use rusoto_core::{request::BufferedHttpResponse, Client, HttpClient, RusotoError};
 use rusoto_credential::{AwsCredentials, StaticProvider};
 use rusoto_s3::{
-    CopyObjectRequest, DeleteObjectRequest, GetObjectRequest, HeadObjectRequest,
-    ListObjectsRequest, PutObjectRequest, S3Client as S3Inner, S3,
+    AbortMultipartUploadRequest, BucketLifecycleConfiguration, CORSConfiguration,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart as S3CompletedPart,
+    CopyObjectRequest, CreateBucketConfiguration, CreateBucketRequest,
+    CreateMultipartUploadRequest, Delete, DeleteBucketCorsRequest, DeleteBucketLifecycleRequest,
+    DeleteBucketRequest, DeleteObjectRequest, DeleteObjectTaggingRequest, DeleteObjectsRequest,
+    GetBucketAclRequest, GetBucketCorsRequest, GetBucketLifecycleConfigurationRequest,
+    GetBucketLocationRequest, GetObjectAclRequest, GetObjectRequest, GetObjectTaggingRequest,
+    GlacierJobParameters, Grant, HeadBucketRequest, HeadObjectRequest, ListObjectVersionsRequest,
+    ListObjectsRequest, ListObjectsV2Request, ObjectIdentifier, PutBucketAclRequest,
+    PutBucketCorsRequest, PutBucketLifecycleConfigurationRequest, PutObjectAclRequest,
+    PutObjectError, PutObjectRequest, PutObjectTaggingRequest, RestoreObjectRequest,
+    RestoreRequest, S3Client as S3Inner, StreamingBody, Tag, Tagging, UploadPartCopyRequest,
+    UploadPartRequest, S3,
 };
-use rusoto_signature::Region;
+use rusoto_signature::{Region, SignedRequest};
 
-use crate::{prelude::*, types, GetAsBufferResp, ListDetailsResp, ListOptions, PutOrCopyOptions};
+use crate::{
+    awos, prelude::*, types, Acl, AppendResp, BucketInfo, ByteRange, CompleteMultipartResp,
+    CompletedPart, CopyResp, CorsRule, CreateBucketOptions, DeleteError, DeleteMultiResp,
+    GetAsBufferResp, GetOptions, GetStreamResp, LifecycleRule, ListDetailsResp, ListOptions,
+    ListVersionsOptions, ListVersionsResp, ObjectMeta, ParseError, PutOrCopyOptions, PutResp,
+    RestoreOptions, ServerSideEncryption,
+};
 
 use crate::AwosApi;
+
+const ALL_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AllUsers";
+
+/// rusoto 的 StreamingBody 要求 Stream 为 Sync, 而 ByteStream 只要求 Send。
+/// 只通过 &mut 访问 Mutex 中的 ByteStream, 不会真正加锁。
+struct SyncByteStream(Mutex<ByteStream>);
+
+impl Stream for SyncByteStream {
+    type Item = std::io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self
+            .get_mut()
+            .0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        Pin::new(stream).poll_next(cx)
+    }
+}
+
 pub(crate) struct S3Client {
     pub(crate) inner: S3Inner,
+    // 与 inner 共用, 用于发送 rusoto 不支持的请求, 如条件 PutObject
+    client: Client,
     pub(crate) bucket: String,
     pub(crate) region: Region,
     // Used in generate Presigned url
@@ -25,13 +73,26 @@ macro_rules! take_and_to_owned {
         $rqst.$item = $opts.$item.take().map(|item| item.to_owned());
     };
 }
-macro_rules! take_headers {
-    ($headers:ident, $resp:ident, $($item:ident),+) => {
-        $(
-            if let Some(_item) = $resp.$item.take() {
-                $headers.insert(stringify!($item).replace("_", "_"), _item.to_string());
+
+/// 设置请求中服务端加密相关的字段, 适用于 PutObject/CopyObject/CreateMultipartUpload。
+macro_rules! set_encryption {
+    ($rqst:ident, $encryption:expr) => {
+        match $encryption {
+            Some(ServerSideEncryption::Managed) => {
+                $rqst.server_side_encryption = Some("AES256".to_owned());
+            }
+            Some(ServerSideEncryption::Kms { key_id }) => {
+                $rqst.server_side_encryption = Some("aws:kms".to_owned());
+                $rqst.ssekms_key_id = key_id;
+            }
+            Some(ServerSideEncryption::Customer { key }) => {
+                let [algorithm, key, key_md5] = types::sse_customer_values(&key);
+                $rqst.sse_customer_algorithm = Some(algorithm);
+                $rqst.sse_customer_key = Some(key);
+                $rqst.sse_customer_key_md5 = Some(key_md5);
             }
-        )+
+            None => (),
+        }
     };
 }
 
@@ -52,15 +113,192 @@ impl S3Client {
         let request_dispatcher = HttpClient::new().expect("failed to create request dispatcher");
         let region = Region::Custom {
             name: "CN".to_owned(),
-            endpoint: endpoint,
+            endpoint,
         };
+        let client = Client::new_with(credentials_provider, request_dispatcher);
         Ok(Self {
-            inner: S3Inner::new_with(request_dispatcher, credentials_provider, region.to_owned()),
+            inner: S3Inner::new_with_client(client.clone(), region.to_owned()),
+            client,
             bucket,
             region,
             credentials,
         })
     }
+
+    /// 读取 copy 源 Object 的元数据, 源 Bucket/版本取自 opts。
+    async fn head_source(&self, src: &str, opts: &PutOrCopyOptions<'_>) -> Result<ObjectMeta> {
+        let rqst = HeadObjectRequest {
+            bucket: opts.src_bucket.unwrap_or(&self.bucket).to_owned(),
+            key: src.to_owned(),
+            version_id: opts
+                .src_version_id
+                .map(|_version_id| _version_id.to_owned()),
+            ..Default::default()
+        };
+        self.inner.head_object(rqst).await?.try_into()
+    }
+
+    /// S3 只有 PutObject 支持条件写入, CopyObject/CreateMultipartUpload 设置了条件时直接拒绝。
+    fn reject_put_conditions(opts: &PutOrCopyOptions<'_>) -> Result<()> {
+        if opts.has_put_conditions() {
+            Err(Error::Unsupported {
+                msg: "S3 only supports conditional writes in put and put_stream".to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 发送 PutObject, 设置了 If-Match/If-None-Match 时不经过 rusoto 的 put_object。
+    async fn send_put_object(
+        &self,
+        rqst: PutObjectRequest,
+        conditions: PutConditions<'_>,
+    ) -> Result<PutResp> {
+        if conditions.if_match.is_none() && conditions.if_none_match.is_none() {
+            let output = self.inner.put_object(rqst).await?;
+            return Ok(PutResp {
+                e_tag: output
+                    .e_tag
+                    .map(|_e_tag| types::trim_etag(&_e_tag).to_owned())
+                    .unwrap_or_default(),
+                version_id: output.version_id,
+            });
+        }
+        let request = conditional_put_request(&self.region, rqst, conditions);
+        let mut resp = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::<PutObjectError>::from)?;
+        if resp.status.is_success() {
+            Ok(PutResp {
+                e_tag: resp
+                    .headers
+                    .get("ETag")
+                    .map(|_e_tag| types::trim_etag(_e_tag).to_owned())
+                    .unwrap_or_default(),
+                version_id: resp.headers.get("x-amz-version-id").cloned(),
+            })
+        } else {
+            let resp = resp
+                .buffer()
+                .await
+                .map_err(RusotoError::<PutObjectError>::from)?;
+            Err(conditional_put_error(resp))
+        }
+    }
+}
+
+/// PutObject 的写入条件, forbid_overwrite 等同于 If-None-Match: *。
+#[derive(Default)]
+struct PutConditions<'a> {
+    if_match: Option<&'a str>,
+    if_none_match: Option<&'a str>,
+}
+
+impl<'a> PutConditions<'a> {
+    fn new(opts: &PutOrCopyOptions<'a>) -> Result<Self> {
+        opts.check_put_conditions(false)?;
+        Ok(Self {
+            if_match: opts.if_match,
+            if_none_match: Some("*").filter(|_| opts.forbids_overwrite()),
+        })
+    }
+}
+
+/// rusoto 0.46 的 PutObjectRequest 没有 If-Match/If-None-Match, 按 rusoto put_object 的方式构造请求,
+/// 只处理 put/put_stream 会设置的字段。
+fn conditional_put_request(
+    region: &Region,
+    rqst: PutObjectRequest,
+    conditions: PutConditions<'_>,
+) -> SignedRequest {
+    let request_uri = format!("/{}/{}", rqst.bucket, rqst.key);
+    let mut request = SignedRequest::new("PUT", "s3", region, &request_uri);
+    request.add_optional_header("x-amz-acl", rqst.acl.as_ref());
+    request.add_optional_header("Cache-Control", rqst.cache_control.as_ref());
+    request.add_optional_header("Content-Disposition", rqst.content_disposition.as_ref());
+    request.add_optional_header("Content-Encoding", rqst.content_encoding.as_ref());
+    request.add_optional_header("Content-Length", rqst.content_length.as_ref());
+    request.add_optional_header("Content-Type", rqst.content_type.as_ref());
+    for (_name, _value) in rqst.metadata.iter().flatten() {
+        request.add_header(format!("x-amz-meta-{}", _name), _value);
+    }
+    request.add_optional_header(
+        "x-amz-server-side-encryption-customer-algorithm",
+        rqst.sse_customer_algorithm.as_ref(),
+    );
+    request.add_optional_header(
+        "x-amz-server-side-encryption-customer-key",
+        rqst.sse_customer_key.as_ref(),
+    );
+    request.add_optional_header(
+        "x-amz-server-side-encryption-customer-key-MD5",
+        rqst.sse_customer_key_md5.as_ref(),
+    );
+    request.add_optional_header(
+        "x-amz-server-side-encryption-aws-kms-key-id",
+        rqst.ssekms_key_id.as_ref(),
+    );
+    request.add_optional_header(
+        "x-amz-server-side-encryption",
+        rqst.server_side_encryption.as_ref(),
+    );
+    request.add_optional_header("x-amz-storage-class", rqst.storage_class.as_ref());
+    request.add_optional_header("x-amz-tagging", rqst.tagging.as_ref());
+    request.add_optional_header("If-Match", conditions.if_match);
+    request.add_optional_header("If-None-Match", conditions.if_none_match);
+    if let Some(_body) = rqst.body {
+        request.set_payload_stream(_body);
+    }
+    request
+}
+
+/// 条件 PutObject 的错误转换。除 412 外, 并发写入同一 Key 的冲突 (409 ConditionalRequestConflict)
+/// 和 If-Match 的目标不存在 (404 NoSuchKey) 也视为条件不满足。
+fn conditional_put_error(resp: BufferedHttpResponse) -> Error {
+    let code = types::read_xml_text(resp.body_as_str(), b"Code")
+        .ok()
+        .flatten();
+    match (resp.status.as_u16(), code.as_deref()) {
+        (409, Some("ConditionalRequestConflict")) | (404, Some("NoSuchKey")) => {
+            Error::PreconditionFailed
+        }
+        _ => resp.into(),
+    }
+}
+
+/// S3 的 Canned ACL。S3 没有 default, 返回 None, 即不设置 x-amz-acl, 由 S3 的默认权限决定。
+fn s3_canned_acl(acl: Acl) -> Option<String> {
+    match acl {
+        Acl::Default => None,
+        _acl => Some(_acl.to_string()),
+    }
+}
+
+/// 根据 AllUsers 组的 Grants 推断 Canned ACL
+fn acl_from_grants(grants: Option<Vec<Grant>>) -> Acl {
+    let permissions: Vec<_> = grants
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|_grant| {
+            _grant
+                .grantee
+                .as_ref()
+                .and_then(|_grantee| _grantee.uri.as_deref())
+                == Some(ALL_USERS_URI)
+        })
+        .filter_map(|_grant| _grant.permission)
+        .collect();
+    let has = |permission: &str| permissions.iter().any(|_p| _p == permission);
+    if has("FULL_CONTROL") || (has("READ") && has("WRITE")) {
+        Acl::PublicReadWrite
+    } else if has("READ") {
+        Acl::PublicRead
+    } else {
+        Acl::Private
+    }
 }
 
 #[async_trait]
@@ -77,11 +315,26 @@ impl AwosApi for S3Client {
     where
         O: Into<Option<ListOptions<'a>>> + Send,
     {
+        let mut opts = opts.into();
+        if let Some(mut _opts) = opts.take_if(|_opts| _opts.v2) {
+            let mut rqst = ListObjectsV2Request {
+                bucket: self.bucket.to_owned(),
+                fetch_owner: _opts.fetch_owner,
+                ..Default::default()
+            };
+            rqst.max_keys = _opts.max_keys.take().map(|i| i as i64);
+            take_and_to_owned!(rqst, _opts, prefix);
+            take_and_to_owned!(rqst, _opts, delimiter);
+            take_and_to_owned!(rqst, _opts, continuation_token);
+            take_and_to_owned!(rqst, _opts, start_after);
+            let resp = self.inner.list_objects_v2(rqst).await?;
+            return resp.try_into();
+        }
         let mut rqst = ListObjectsRequest {
             bucket: self.bucket.to_owned(),
             ..Default::default()
         };
-        if let Some(mut _opts) = opts.into() {
+        if let Some(mut _opts) = opts {
             rqst.max_keys = _opts.max_keys.take().map(|i| i as i64);
             take_and_to_owned!(rqst, _opts, prefix);
             take_and_to_owned!(rqst, _opts, marker);
@@ -89,35 +342,93 @@ impl AwosApi for S3Client {
         }
         // let rt = tokio::runtime::Runtime::new().unwrap();
         let resp = self.inner.list_objects(rqst).await?;
-        Ok(resp.into())
+        resp.try_into()
+    }
+
+    async fn list_versions<'a, O>(&self, opts: O) -> Result<ListVersionsResp>
+    where
+        O: Into<Option<ListVersionsOptions<'a>>> + Send,
+    {
+        let mut rqst = ListObjectVersionsRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        if let Some(mut _opts) = opts.into() {
+            rqst.max_keys = _opts.max_keys.take().map(|i| i as i64);
+            take_and_to_owned!(rqst, _opts, prefix);
+            take_and_to_owned!(rqst, _opts, key_marker);
+            take_and_to_owned!(rqst, _opts, version_id_marker);
+            take_and_to_owned!(rqst, _opts, delimiter);
+        }
+        self.inner.list_object_versions(rqst).await?.try_into()
     }
 
-    async fn get<'a, S, M, F>(&self, key: S, meta_keys_filter: M) -> Result<types::GetResp>
+    async fn get<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<types::GetResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        let resp = self.get_as_buffer(key, meta_keys_filter).await?;
+        let resp = self.get_as_buffer(key, meta_keys_filter, opts).await?;
         Ok(resp.into())
     }
 
-    async fn get_as_buffer<'a, S, M, F>(
+    async fn get_as_buffer<'a, S, M, F, O>(
         &self,
         key: S,
         meta_keys_filter: M,
+        opts: O,
     ) -> Result<GetAsBufferResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
+    {
+        self.get_stream(key, meta_keys_filter, opts)
+            .await?
+            .into_buffer()
+            .await
+    }
+
+    async fn get_stream<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<GetStreamResp>
+    where
+        S: AsRef<str> + Send,
+        M: Into<Option<F>> + Send,
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        let rqst = GetObjectRequest {
+        let mut rqst = GetObjectRequest {
             bucket: self.bucket.to_owned(),
             key: key.as_ref().to_owned(),
             ..Default::default()
         };
-        let mut resp = GetAsBufferResp::from_get_output(self.inner.get_object(rqst).await?).await;
+        if let Some(_opts) = opts.into() {
+            rqst.range = _opts.range.map(|_range| _range.to_string());
+            rqst.if_match = _opts.if_match;
+            rqst.if_none_match = _opts.if_none_match;
+            rqst.if_modified_since = _opts.if_modified_since.map(types::http_date);
+            rqst.if_unmodified_since = _opts.if_unmodified_since.map(types::http_date);
+            rqst.version_id = _opts.version_id;
+            if let Some(_key) = _opts.sse_customer_key {
+                let [algorithm, key, key_md5] = types::sse_customer_values(&_key);
+                rqst.sse_customer_algorithm = Some(algorithm);
+                rqst.sse_customer_key = Some(key);
+                rqst.sse_customer_key_md5 = Some(key_md5);
+            }
+        }
+        let mut resp = GetStreamResp::from_get_output(self.inner.get_object(rqst).await?);
         if let Some(_meta_keys_filter) = meta_keys_filter.into() {
             let _filter = _meta_keys_filter.into_iter().collect();
             resp.filter(_filter);
@@ -125,111 +436,734 @@ impl AwosApi for S3Client {
         Ok(resp)
     }
 
-    async fn head<S>(&self, key: S) -> Result<HashMap<String, String>>
+    async fn head<'a, S, V>(&self, key: S, version_id: V) -> Result<ObjectMeta>
     where
         S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
         let rqst = HeadObjectRequest {
             bucket: self.bucket.to_owned(),
             key: key.as_ref().to_owned(),
+            version_id: version_id.into().map(ToOwned::to_owned),
             ..Default::default()
         };
-        let mut _resp = self.inner.head_object(rqst).await?;
-        let mut ret = HashMap::new();
-        take_headers!(
-            ret,
-            _resp,
-            accept_ranges,
-            cache_control,
-            bucket_key_enabled,
-            content_length,
-            content_disposition,
-            content_encoding,
-            content_language,
-            content_type,
-            delete_marker,
-            e_tag,
-            expiration,
-            expires,
-            last_modified,
-            object_lock_legal_hold_status,
-            object_lock_mode,
-            object_lock_retain_until_date,
-            replication_status,
-            request_charged,
-            restore,
-            sse_customer_algorithm,
-            sse_customer_key_md5,
-            ssekms_key_id,
-            server_side_encryption,
-            storage_class
-        );
-        Ok(ret)
+        self.inner.head_object(rqst).await?.try_into()
     }
 
-    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<()>
+    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<PutResp>
     where
         S: AsRef<str> + Send,
         D: Into<Box<[u8]>> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send,
     {
-        let buf = data.into().to_vec();
+        let buf = data.into().into_vec();
         let mut rqst = PutObjectRequest {
             bucket: self.bucket.to_owned(),
             key: key.as_ref().to_owned(),
             body: Some(buf.into()),
             ..Default::default()
         };
+        let mut conditions = PutConditions::default();
         if let Some(mut _opts) = opts.into() {
+            conditions = PutConditions::new(&_opts)?;
             rqst.metadata = _opts.meta.take();
             take_and_to_owned!(rqst, _opts, content_type);
             take_and_to_owned!(rqst, _opts, cache_control);
             take_and_to_owned!(rqst, _opts, content_disposition);
             take_and_to_owned!(rqst, _opts, content_encoding);
+            rqst.tagging = _opts.tagging();
+            rqst.acl = _opts.acl.and_then(s3_canned_acl);
+            rqst.storage_class = _opts
+                .storage_class
+                .map(|_class| _class.as_s3_str().to_owned());
+            set_encryption!(rqst, _opts.encryption.take());
         }
-        self.inner.put_object(rqst).await?;
-        Ok(())
+        self.send_put_object(rqst, conditions).await
     }
 
-    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<()>
+    async fn put_stream<'a, S, O>(&self, key: S, body: ByteStream, opts: O) -> Result<PutResp>
     where
-        S1: Into<String> + Send,
-        S2: AsRef<str> + Send,
+        S: AsRef<str> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send,
     {
-        let mut rqst = CopyObjectRequest {
+        let opts = opts.into();
+        // 大小未知时通过 put_multipart 上传, 此时不支持写入条件
+        let size = match body.size_hint() {
+            Some(_size) => _size,
+            None => {
+                return self
+                    .put_multipart(key, body.into_async_read(), None, opts)
+                    .await
+                    .map(Into::into);
+            }
+        };
+        let mut rqst = PutObjectRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            content_length: Some(size as i64),
+            body: Some(StreamingBody::new_with_size(
+                SyncByteStream(Mutex::new(body)),
+                size,
+            )),
             ..Default::default()
         };
-        if let Some(mut _opts) = opts.into() {
+        let mut conditions = PutConditions::default();
+        if let Some(mut _opts) = opts {
+            conditions = PutConditions::new(&_opts)?;
             rqst.metadata = _opts.meta.take();
             take_and_to_owned!(rqst, _opts, content_type);
-            take_and_to_owned!(rqst, _opts, content_encoding);
-            take_and_to_owned!(rqst, _opts, content_disposition);
             take_and_to_owned!(rqst, _opts, cache_control);
+            take_and_to_owned!(rqst, _opts, content_disposition);
+            take_and_to_owned!(rqst, _opts, content_encoding);
+            rqst.tagging = _opts.tagging();
+            rqst.acl = _opts.acl.and_then(s3_canned_acl);
+            rqst.storage_class = _opts
+                .storage_class
+                .map(|_class| _class.as_s3_str().to_owned());
+            set_encryption!(rqst, _opts.encryption.take());
         }
-        Ok(())
+        self.send_put_object(rqst, conditions).await
+    }
+
+    async fn append<'a, S, D, O>(
+        &self,
+        _key: S,
+        _position: u64,
+        _data: D,
+        _opts: O,
+    ) -> Result<AppendResp>
+    where
+        S: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        Err(Error::Unsupported {
+            msg: "S3 does not support AppendObject".to_owned(),
+        })
+    }
+
+    async fn put_symlink<'a, S1, S2, O>(&self, _key: S1, _target: S2, _opts: O) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        Err(Error::Unsupported {
+            msg: "S3 does not support Symlink".to_owned(),
+        })
     }
 
-    async fn del<S>(&self, key: S) -> Result<()>
+    async fn get_symlink<S>(&self, _key: S) -> Result<String>
     where
         S: AsRef<str> + Send,
+    {
+        Err(Error::Unsupported {
+            msg: "S3 does not support Symlink".to_owned(),
+        })
+    }
+
+    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let mut opts = opts.into().unwrap_or_default();
+        Self::reject_put_conditions(&opts)?;
+        let mut rqst = CopyObjectRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            copy_source: types::copy_source(
+                opts.src_bucket.unwrap_or(&self.bucket),
+                &src.into(),
+                opts.src_version_id,
+            ),
+            metadata_directive: Some(opts.metadata_directive().to_string()),
+            ..Default::default()
+        };
+        rqst.metadata = opts.meta.take();
+        take_and_to_owned!(rqst, opts, content_type);
+        take_and_to_owned!(rqst, opts, content_encoding);
+        take_and_to_owned!(rqst, opts, content_disposition);
+        take_and_to_owned!(rqst, opts, cache_control);
+        rqst.acl = opts.acl.and_then(s3_canned_acl);
+        rqst.storage_class = opts
+            .storage_class
+            .map(|_class| _class.as_s3_str().to_owned());
+        set_encryption!(rqst, opts.encryption.take());
+        if let Some(_key) = opts.src_sse_customer_key {
+            let [algorithm, key, key_md5] = types::sse_customer_values(_key);
+            rqst.copy_source_sse_customer_algorithm = Some(algorithm);
+            rqst.copy_source_sse_customer_key = Some(key);
+            rqst.copy_source_sse_customer_key_md5 = Some(key_md5);
+        }
+        if let Some(_tagging) = opts.tagging() {
+            rqst.tagging = Some(_tagging);
+            rqst.tagging_directive = Some("REPLACE".to_owned());
+        }
+        let output = self.inner.copy_object(rqst).await?;
+        Ok(CopyResp {
+            e_tag: output
+                .copy_object_result
+                .and_then(|_result| _result.e_tag)
+                .map(|_e_tag| types::trim_etag(&_e_tag).to_owned())
+                .unwrap_or_default(),
+            version_id: output.version_id,
+        })
+    }
+
+    async fn copy_large<'a, S1, S2, N, O>(
+        &self,
+        src: S1,
+        key: S2,
+        part_size: N,
+        opts: O,
+    ) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let (src, key) = (src.into(), key.as_ref());
+        let opts = opts.into().unwrap_or_default();
+        Self::reject_put_conditions(&opts)?;
+        opts.check_multipart_encryption()?;
+        let src_meta = self.head_source(&src, &opts).await?;
+        awos::copy_multipart(self, &src, key, src_meta, part_size.into(), opts).await
+    }
+
+    async fn del<'a, S, V>(&self, key: S, version_id: V) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
         let del_request = DeleteObjectRequest {
             bucket: self.bucket.to_owned(),
             key: key.as_ref().to_owned(),
+            version_id: version_id.into().map(ToOwned::to_owned),
             ..Default::default()
         };
         self.inner.delete_object(del_request).await?;
         Ok(())
     }
 
-    async fn del_multi<S>(&self, keys: &[S]) -> Result<()>
+    async fn del_multi<S>(&self, keys: &[S], quiet: bool) -> Result<DeleteMultiResp>
     where
         S: AsRef<str> + Sync,
     {
-        for key in keys.into_iter() {
-            self.del(key).await?;
+        let mut ret = DeleteMultiResp::default();
+        for chunk in keys.chunks(types::MAX_DELETE_KEYS) {
+            let rqst = DeleteObjectsRequest {
+                bucket: self.bucket.to_owned(),
+                delete: Delete {
+                    objects: chunk
+                        .iter()
+                        .map(|key| ObjectIdentifier {
+                            key: key.as_ref().to_owned(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    quiet: Some(quiet),
+                },
+                ..Default::default()
+            };
+            let output = self.inner.delete_objects(rqst).await?;
+            ret.deleted.extend(
+                output
+                    .deleted
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|_deleted| _deleted.key),
+            );
+            ret.errors
+                .extend(
+                    output
+                        .errors
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|_err| DeleteError {
+                            key: _err.key.unwrap_or_default(),
+                            code: _err.code.unwrap_or_default(),
+                            message: _err.message.unwrap_or_default(),
+                        }),
+                );
         }
+        Ok(ret)
+    }
+
+    async fn restore<S, O>(&self, key: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<RestoreOptions>> + Send,
+    {
+        let opts = opts.into().unwrap_or_default();
+        let rqst = RestoreObjectRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            restore_request: Some(RestoreRequest {
+                days: Some(opts.days.unwrap_or(1) as i64),
+                glacier_job_parameters: opts.tier.map(|_tier| GlacierJobParameters {
+                    tier: _tier.to_string(),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        self.inner.restore_object(rqst).await?;
+        Ok(())
+    }
+
+    async fn get_tagging<S>(&self, key: S) -> Result<HashMap<String, String>>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = GetObjectTaggingRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            ..Default::default()
+        };
+        let output = self.inner.get_object_tagging(rqst).await?;
+        Ok(output
+            .tag_set
+            .into_iter()
+            .map(|_tag| (_tag.key, _tag.value))
+            .collect())
+    }
+
+    async fn put_tagging<S>(&self, key: S, tags: HashMap<String, String>) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = PutObjectTaggingRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            tagging: Tagging {
+                tag_set: tags
+                    .into_iter()
+                    .map(|(key, value)| Tag { key, value })
+                    .collect(),
+            },
+            ..Default::default()
+        };
+        self.inner.put_object_tagging(rqst).await?;
+        Ok(())
+    }
+
+    async fn delete_tagging<S>(&self, key: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = DeleteObjectTaggingRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            ..Default::default()
+        };
+        self.inner.delete_object_tagging(rqst).await?;
+        Ok(())
+    }
+
+    async fn get_object_acl<S>(&self, key: S) -> Result<Acl>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = GetObjectAclRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            ..Default::default()
+        };
+        let output = self.inner.get_object_acl(rqst).await?;
+        Ok(acl_from_grants(output.grants))
+    }
+
+    async fn put_object_acl<S>(&self, key: S, acl: Acl) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        if acl == Acl::Default {
+            return Err(Error::Unsupported {
+                msg: "S3 does not support Acl::Default".to_owned(),
+            });
+        }
+        let rqst = PutObjectAclRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            acl: s3_canned_acl(acl),
+            ..Default::default()
+        };
+        self.inner.put_object_acl(rqst).await?;
+        Ok(())
+    }
+
+    async fn get_bucket_acl(&self) -> Result<Acl> {
+        let rqst = GetBucketAclRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        let output = self.inner.get_bucket_acl(rqst).await?;
+        Ok(acl_from_grants(output.grants))
+    }
+
+    async fn put_bucket_acl(&self, acl: Acl) -> Result<()> {
+        types::check_bucket_acl(acl)?;
+        let rqst = PutBucketAclRequest {
+            bucket: self.bucket.to_owned(),
+            acl: s3_canned_acl(acl),
+            ..Default::default()
+        };
+        self.inner.put_bucket_acl(rqst).await?;
+        Ok(())
+    }
+
+    async fn create_bucket<'a, S, O>(&self, bucket: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<CreateBucketOptions<'a>>> + Send,
+    {
+        let opts = opts.into().unwrap_or_default();
+        if let Some(_acl) = opts.acl {
+            types::check_bucket_acl(_acl)?;
+        }
+        let rqst = CreateBucketRequest {
+            bucket: bucket.as_ref().to_owned(),
+            acl: opts.acl.and_then(s3_canned_acl),
+            create_bucket_configuration: opts.region.map(|_region| CreateBucketConfiguration {
+                location_constraint: Some(_region.to_owned()),
+            }),
+            ..Default::default()
+        };
+        self.inner.create_bucket(rqst).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket<S>(&self, bucket: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = DeleteBucketRequest {
+            bucket: bucket.as_ref().to_owned(),
+            ..Default::default()
+        };
+        self.inner.delete_bucket(rqst).await?;
+        Ok(())
+    }
+
+    async fn list_buckets<'a, P>(&self, prefix: P) -> Result<Vec<BucketInfo>>
+    where
+        P: Into<Option<&'a str>> + Send,
+    {
+        let prefix = prefix.into().unwrap_or_default();
+        let output = self.inner.list_buckets().await?;
+        let owner = types::from_s3_owner(output.owner);
+        output
+            .buckets
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|_bucket| {
+                _bucket
+                    .name
+                    .as_deref()
+                    .is_some_and(|_name| _name.starts_with(prefix))
+            })
+            .map(|_bucket| {
+                Ok(BucketInfo {
+                    name: _bucket.name.unwrap_or_default(),
+                    creation_date: _bucket
+                        .creation_date
+                        .map(|_date| types::parse_iso8601(&_date))
+                        .transpose()?,
+                    owner: owner.clone(),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    async fn bucket_exists<S>(&self, bucket: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = HeadBucketRequest {
+            bucket: bucket.as_ref().to_owned(),
+            ..Default::default()
+        };
+        match self.inner.head_bucket(rqst).await {
+            Ok(_) => Ok(true),
+            Err(e) => match Error::from(e) {
+                Error::Io(_e) if _e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+                _e => Err(_e),
+            },
+        }
+    }
+
+    async fn get_bucket_info<S>(&self, bucket: S) -> Result<BucketInfo>
+    where
+        S: AsRef<str> + Send,
+    {
+        let bucket = bucket.as_ref();
+        let location = self.get_bucket_location(bucket).await?;
+        let rqst = GetBucketAclRequest {
+            bucket: bucket.to_owned(),
+            ..Default::default()
+        };
+        let output = self.inner.get_bucket_acl(rqst).await?;
+        let creation_date = self
+            .list_buckets(bucket)
+            .await?
+            .into_iter()
+            .find(|_bucket| _bucket.name == bucket)
+            .and_then(|_bucket| _bucket.creation_date);
+        Ok(BucketInfo {
+            name: bucket.to_owned(),
+            location,
+            creation_date,
+            storage_class: String::new(),
+            acl: Some(acl_from_grants(output.grants)),
+            owner: types::from_s3_owner(output.owner),
+        })
+    }
+
+    async fn get_bucket_location<S>(&self, bucket: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        let rqst = GetBucketLocationRequest {
+            bucket: bucket.as_ref().to_owned(),
+            ..Default::default()
+        };
+        let output = self.inner.get_bucket_location(rqst).await?;
+        // us-east-1 的 LocationConstraint 为空
+        Ok(output
+            .location_constraint
+            .filter(|_location| !_location.is_empty())
+            .unwrap_or_else(|| "us-east-1".to_owned()))
+    }
+
+    async fn get_bucket_lifecycle(&self) -> Result<Vec<LifecycleRule>> {
+        let rqst = GetBucketLifecycleConfigurationRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        let output = match self.inner.get_bucket_lifecycle_configuration(rqst).await {
+            Ok(_output) => _output,
+            Err(RusotoError::Unknown(ref _resp))
+                if _resp.body_as_str().contains("NoSuchLifecycleConfiguration") =>
+            {
+                return Ok(Vec::new())
+            }
+            Err(e) => return Err(e.into()),
+        };
+        output
+            .rules
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    async fn put_bucket_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        let rqst = PutBucketLifecycleConfigurationRequest {
+            bucket: self.bucket.to_owned(),
+            lifecycle_configuration: Some(BucketLifecycleConfiguration {
+                rules: rules.into_iter().map(Into::into).collect(),
+            }),
+            ..Default::default()
+        };
+        self.inner.put_bucket_lifecycle_configuration(rqst).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket_lifecycle(&self) -> Result<()> {
+        let rqst = DeleteBucketLifecycleRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        self.inner.delete_bucket_lifecycle(rqst).await?;
+        Ok(())
+    }
+
+    async fn get_bucket_cors(&self) -> Result<Vec<CorsRule>> {
+        let rqst = GetBucketCorsRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        let output = match self.inner.get_bucket_cors(rqst).await {
+            Ok(_output) => _output,
+            Err(RusotoError::Unknown(ref _resp))
+                if _resp.body_as_str().contains("NoSuchCORSConfiguration") =>
+            {
+                return Ok(Vec::new())
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(output
+            .cors_rules
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn put_bucket_cors(&self, rules: Vec<CorsRule>) -> Result<()> {
+        let rqst = PutBucketCorsRequest {
+            bucket: self.bucket.to_owned(),
+            cors_configuration: CORSConfiguration {
+                cors_rules: rules.into_iter().map(Into::into).collect(),
+            },
+            ..Default::default()
+        };
+        self.inner.put_bucket_cors(rqst).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket_cors(&self) -> Result<()> {
+        let rqst = DeleteBucketCorsRequest {
+            bucket: self.bucket.to_owned(),
+            ..Default::default()
+        };
+        self.inner.delete_bucket_cors(rqst).await?;
+        Ok(())
+    }
+
+    async fn initiate_multipart<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let mut rqst = CreateMultipartUploadRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            ..Default::default()
+        };
+        if let Some(mut _opts) = opts.into() {
+            Self::reject_put_conditions(&_opts)?;
+            _opts.check_multipart_encryption()?;
+            rqst.metadata = _opts.meta.take();
+            take_and_to_owned!(rqst, _opts, content_type);
+            take_and_to_owned!(rqst, _opts, cache_control);
+            take_and_to_owned!(rqst, _opts, content_disposition);
+            take_and_to_owned!(rqst, _opts, content_encoding);
+            rqst.tagging = _opts.tagging();
+            rqst.acl = _opts.acl.and_then(s3_canned_acl);
+            rqst.storage_class = _opts
+                .storage_class
+                .map(|_class| _class.as_s3_str().to_owned());
+            set_encryption!(rqst, _opts.encryption.take());
+        }
+        let resp = self.inner.create_multipart_upload(rqst).await?;
+        resp.upload_id.ok_or_else(|| {
+            Error::Parse(ParseError::InvalidFormat {
+                msg: "UploadId not found in CreateMultipartUploadOutput".to_owned(),
+            })
+        })
+    }
+
+    async fn upload_part<S1, S2, D>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        data: D,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+    {
+        let buf = data.into().into_vec();
+        let rqst = UploadPartRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            upload_id: upload_id.as_ref().to_owned(),
+            part_number: part_number as i64,
+            content_length: Some(buf.len() as i64),
+            body: Some(buf.into()),
+            ..Default::default()
+        };
+        let resp = self.inner.upload_part(rqst).await?;
+        Ok(resp.e_tag.unwrap_or_default())
+    }
+
+    async fn upload_part_copy<'a, S1, S2, S3, R, O>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        src: S3,
+        range: R,
+        opts: O,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: Into<String> + Send,
+        R: Into<Option<ByteRange>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let opts = opts.into().unwrap_or_default();
+        let rqst = UploadPartCopyRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            upload_id: upload_id.as_ref().to_owned(),
+            part_number: part_number as i64,
+            copy_source: types::copy_source(
+                opts.src_bucket.unwrap_or(&self.bucket),
+                &src.into(),
+                opts.src_version_id,
+            ),
+            copy_source_range: range.into().map(|_range| _range.to_string()),
+            ..Default::default()
+        };
+        let resp = self.inner.upload_part_copy(rqst).await?;
+        Ok(resp
+            .copy_part_result
+            .and_then(|_result| _result.e_tag)
+            .unwrap_or_default())
+    }
+
+    async fn complete_multipart<S1, S2>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let parts = parts
+            .into_iter()
+            .map(|_part| S3CompletedPart {
+                e_tag: Some(_part.e_tag),
+                part_number: Some(_part.part_number as i64),
+            })
+            .collect();
+        let rqst = CompleteMultipartUploadRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            upload_id: upload_id.as_ref().to_owned(),
+            multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+            ..Default::default()
+        };
+        let resp = self.inner.complete_multipart_upload(rqst).await?;
+        Ok(CompleteMultipartResp {
+            e_tag: resp.e_tag.unwrap_or_default(),
+            version_id: resp.version_id,
+        })
+    }
+
+    async fn abort_multipart<S1, S2>(&self, key: S1, upload_id: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let rqst = AbortMultipartUploadRequest {
+            bucket: self.bucket.to_owned(),
+            key: key.as_ref().to_owned(),
+            upload_id: upload_id.as_ref().to_owned(),
+            ..Default::default()
+        };
+        self.inner.abort_multipart_upload(rqst).await?;
         Ok(())
     }
 
@@ -259,6 +1193,81 @@ impl AwosApi for S3Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    fn error_response(status: u16, code: &str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            body: format!("<Error><Code>{}</Code></Error>", code).into(),
+            headers: Default::default(),
+        }
+    }
+
+    #[test]
+    fn conditional_put_request_test() {
+        let opts = PutOrCopyOptions {
+            forbid_overwrite: Some(true),
+            ..Default::default()
+        };
+        let conditions = PutConditions::new(&opts).unwrap();
+        let rqst = PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            content_type: Some("text/plain".to_owned()),
+            metadata: Some(vec![("k".to_owned(), "v".to_owned())].into_iter().collect()),
+            ..Default::default()
+        };
+        let request = conditional_put_request(&Region::UsEast1, rqst, conditions);
+        assert_eq!(request.path, "/bucket/key");
+        assert_eq!(request.headers["if-none-match"], vec![b"*".to_vec()]);
+        assert_eq!(
+            request.headers["content-type"],
+            vec![b"text/plain".to_vec()]
+        );
+        assert_eq!(request.headers["x-amz-meta-k"], vec![b"v".to_vec()]);
+        assert!(!request.headers.contains_key("if-match"));
+
+        let opts = PutOrCopyOptions {
+            if_match: Some("etag"),
+            ..Default::default()
+        };
+        let conditions = PutConditions::new(&opts).unwrap();
+        assert_eq!(conditions.if_match, Some("etag"));
+        assert_eq!(conditions.if_none_match, None);
+        let opts = PutOrCopyOptions {
+            if_none_match: Some("etag"),
+            ..Default::default()
+        };
+        assert!(matches!(
+            PutConditions::new(&opts),
+            Err(Error::Unsupported { .. })
+        ));
+        assert!(S3Client::reject_put_conditions(&PutOrCopyOptions::default()).is_ok());
+    }
+
+    #[test]
+    fn s3_canned_acl_test() {
+        assert_eq!(
+            s3_canned_acl(Acl::PublicRead).as_deref(),
+            Some("public-read")
+        );
+        assert_eq!(s3_canned_acl(Acl::Private).as_deref(), Some("private"));
+        assert_eq!(s3_canned_acl(Acl::Default), None);
+    }
+
+    #[test]
+    fn conditional_put_error_test() {
+        let error = conditional_put_error(error_response(412, "PreconditionFailed"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        let error = conditional_put_error(error_response(409, "ConditionalRequestConflict"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        let error = conditional_put_error(error_response(404, "NoSuchKey"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        let error = conditional_put_error(error_response(404, "NoSuchBucket"));
+        assert_eq!(error.io_kind(), Some(std::io::ErrorKind::NotFound));
+        let error = conditional_put_error(error_response(409, "OperationAborted"));
+        assert!(matches!(error, Error::Internal { .. }));
+    }
+
     #[tokio::test]
     async fn s3_client_test() {
         let bucket = "s3-test-bucket".to_owned();
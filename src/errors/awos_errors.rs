@@ -2,7 +2,15 @@ use oss_sdk::OSSError;
 use quick_xml::Error as QxmlError;
 use rusoto_core::{request::BufferedHttpResponse, RusotoError};
 use rusoto_s3::{
-    DeleteObjectError, GetObjectError, HeadObjectError, ListObjectsError, PutObjectError,
+    AbortMultipartUploadError, CompleteMultipartUploadError, CopyObjectError, CreateBucketError,
+    CreateMultipartUploadError, DeleteBucketCorsError, DeleteBucketError,
+    DeleteBucketLifecycleError, DeleteObjectError, DeleteObjectTaggingError, DeleteObjectsError,
+    GetBucketAclError, GetBucketCorsError, GetBucketLifecycleConfigurationError,
+    GetBucketLocationError, GetObjectAclError, GetObjectError, GetObjectTaggingError,
+    HeadBucketError, HeadObjectError, ListBucketsError, ListObjectVersionsError, ListObjectsError,
+    ListObjectsV2Error, PutBucketAclError, PutBucketCorsError,
+    PutBucketLifecycleConfigurationError, PutObjectAclError, PutObjectError, PutObjectTaggingError,
+    RestoreObjectError, UploadPartCopyError, UploadPartError,
 };
 use std::{error::Error as StdError, io::ErrorKind, str::Utf8Error, string::FromUtf8Error};
 
@@ -16,6 +24,13 @@ pub enum Error {
     Service,
     /// An error occurs when parsing the response, such as constructing a String from UTF8.
     Parse(ParseError),
+    /// 304 Not Modified, 条件 Get 的 If-None-Match/If-Modified-Since 未满足, 本地缓存仍然有效。
+    NotModified,
+    /// 412 Precondition Failed, If-Match/If-Unmodified-Since 未满足。
+    PreconditionFailed,
+    /// 当前后端不支持该操作, 如 S3 的 append。
+    #[display(fmt = "Unsupported operation: {}", msg)]
+    Unsupported { msg: String },
     /// An error message  from one of our underlying modules. Wrapped up to gracefully handling it.
     #[display(fmt = "{}", msg)]
     Internal { msg: String },
@@ -55,12 +70,20 @@ fn to_error<E>(e: RusotoError<E>) -> Error {
         _ => panic!("Should Not Reach Here"),
     }
 }
+/// 读取 rusoto 未识别的错误响应中的 Code
+fn error_code(resp: &BufferedHttpResponse) -> Option<String> {
+    crate::types::read_xml_text(resp.body_as_str(), b"Code")
+        .ok()
+        .flatten()
+}
 impl From<BufferedHttpResponse> for Error {
     fn from(resp: BufferedHttpResponse) -> Self {
         match resp.status.as_u16() {
             404 => Error::Io(IoError::from(ErrorKind::NotFound)),
             403 => Error::Io(IoError::from(ErrorKind::PermissionDenied)),
             408 => Error::Io(IoError::from(ErrorKind::TimedOut)),
+            304 => Error::NotModified,
+            412 => Error::PreconditionFailed,
             _ => Error::Internal {
                 msg: format!(
                     "Unknown Error. Http Response is: StatusCode:{}, Headers:{:?}, Body:{}",
@@ -77,7 +100,12 @@ impl From<u16> for Error {
         match status_code {
             404 => Error::Io(IoError::from(ErrorKind::NotFound)),
             403 => Error::Io(IoError::from(ErrorKind::PermissionDenied)),
-            _ => panic!("Should Not from this number {}", status_code),
+            408 => Error::Io(IoError::from(ErrorKind::TimedOut)),
+            304 => Error::NotModified,
+            412 => Error::PreconditionFailed,
+            _ => Error::Internal {
+                msg: format!("Unexpected Http StatusCode: {}", status_code),
+            },
         }
     }
 }
@@ -91,6 +119,16 @@ impl From<RusotoError<ListObjectsError>> for Error {
         }
     }
 }
+impl From<RusotoError<ListObjectsV2Error>> for Error {
+    fn from(e: RusotoError<ListObjectsV2Error>) -> Self {
+        match e {
+            RusotoError::Service(ListObjectsV2Error::NoSuchBucket(_msg)) => {
+                Error::Io(IoError::new(ErrorKind::NotFound, _msg))
+            }
+            _ => to_error(e),
+        }
+    }
+}
 impl From<RusotoError<GetObjectError>> for Error {
     fn from(e: RusotoError<GetObjectError>) -> Self {
         match e {
@@ -98,7 +136,7 @@ impl From<RusotoError<GetObjectError>> for Error {
                 Error::Io(IoError::new(ErrorKind::NotFound, msg))
             }
             RusotoError::Service(GetObjectError::InvalidObjectState(msg)) => {
-                Error::Io(IoError::new(ErrorKind::Other, msg))
+                Error::Io(IoError::other(msg))
             }
             _ => to_error(e),
         }
@@ -114,17 +152,124 @@ impl From<RusotoError<HeadObjectError>> for Error {
         }
     }
 }
-impl From<RusotoError<PutObjectError>> for Error {
-    fn from(e: RusotoError<PutObjectError>) -> Self {
-        to_error(e)
+impl From<RusotoError<AbortMultipartUploadError>> for Error {
+    fn from(e: RusotoError<AbortMultipartUploadError>) -> Self {
+        match e {
+            RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(msg)) => {
+                Error::Io(IoError::new(ErrorKind::NotFound, msg))
+            }
+            _ => to_error(e),
+        }
     }
 }
-impl From<RusotoError<DeleteObjectError>> for Error {
-    fn from(e: RusotoError<DeleteObjectError>) -> Self {
-        to_error(e)
+
+impl From<RusotoError<CreateBucketError>> for Error {
+    fn from(e: RusotoError<CreateBucketError>) -> Self {
+        match e {
+            RusotoError::Service(CreateBucketError::BucketAlreadyExists(_))
+            | RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(_)) => {
+                Error::PreconditionFailed
+            }
+            _ => to_error(e),
+        }
+    }
+}
+impl From<RusotoError<HeadBucketError>> for Error {
+    fn from(e: RusotoError<HeadBucketError>) -> Self {
+        match e {
+            RusotoError::Service(HeadBucketError::NoSuchBucket(msg)) => {
+                Error::Io(IoError::new(ErrorKind::NotFound, msg))
+            }
+            _ => to_error(e),
+        }
+    }
+}
+impl From<RusotoError<CopyObjectError>> for Error {
+    fn from(e: RusotoError<CopyObjectError>) -> Self {
+        match e {
+            // 源 Object 为归档类型且未解冻, 与 GetObjectError::InvalidObjectState 一致
+            RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(msg)) => {
+                Error::Io(IoError::other(msg))
+            }
+            _ => to_error(e),
+        }
+    }
+}
+impl From<RusotoError<GetObjectAclError>> for Error {
+    fn from(e: RusotoError<GetObjectAclError>) -> Self {
+        match e {
+            RusotoError::Service(GetObjectAclError::NoSuchKey(msg)) => {
+                Error::Io(IoError::new(ErrorKind::NotFound, msg))
+            }
+            _ => to_error(e),
+        }
+    }
+}
+impl From<RusotoError<PutObjectAclError>> for Error {
+    fn from(e: RusotoError<PutObjectAclError>) -> Self {
+        match e {
+            RusotoError::Service(PutObjectAclError::NoSuchKey(msg)) => {
+                Error::Io(IoError::new(ErrorKind::NotFound, msg))
+            }
+            _ => to_error(e),
+        }
+    }
+}
+impl From<RusotoError<RestoreObjectError>> for Error {
+    fn from(e: RusotoError<RestoreObjectError>) -> Self {
+        match e {
+            // 非归档类型的 Object 不需要解冻
+            RusotoError::Service(RestoreObjectError::ObjectAlreadyInActiveTierError(_)) => {
+                Error::PreconditionFailed
+            }
+            // 解冻正在进行中, 与 OSS 一致
+            RusotoError::Unknown(ref _resp)
+                if error_code(_resp).as_deref() == Some("RestoreAlreadyInProgress") =>
+            {
+                Error::PreconditionFailed
+            }
+            _ => to_error(e),
+        }
     }
 }
 
+/// 没有需要特殊处理的 Service Error 的，统一用 to_error 转换。
+macro_rules! from_rusoto_errors {
+    ($($err:ident),+) => {
+        $(
+            impl From<RusotoError<$err>> for Error {
+                fn from(e: RusotoError<$err>) -> Self {
+                    to_error(e)
+                }
+            }
+        )+
+    };
+}
+from_rusoto_errors!(
+    PutObjectError,
+    DeleteObjectError,
+    DeleteObjectsError,
+    GetObjectTaggingError,
+    PutObjectTaggingError,
+    DeleteObjectTaggingError,
+    GetBucketAclError,
+    PutBucketAclError,
+    CreateMultipartUploadError,
+    UploadPartError,
+    UploadPartCopyError,
+    CompleteMultipartUploadError,
+    ListObjectVersionsError,
+    DeleteBucketError,
+    ListBucketsError,
+    GetBucketLocationError,
+    GetBucketLifecycleConfigurationError,
+    PutBucketLifecycleConfigurationError,
+    DeleteBucketLifecycleError,
+    GetBucketCorsError,
+    PutBucketCorsError,
+    DeleteBucketCorsError
+);
+
 impl From<OSSError> for Error {
     fn from(e: OSSError) -> Self {
         Error::Internal {
@@ -133,6 +278,12 @@ impl From<OSSError> for Error {
     }
 }
 
+impl From<IoError> for Error {
+    fn from(e: IoError) -> Self {
+        Error::Io(e)
+    }
+}
+
 impl From<FromUtf8Error> for Error {
     fn from(e: FromUtf8Error) -> Self {
         Error::Parse(ParseError::UTF8(e.utf8_error()))
@@ -168,3 +319,43 @@ impl StdError for ParseError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_error_test() {
+        let e: Error = RusotoError::Service(RestoreObjectError::ObjectAlreadyInActiveTierError(
+            "not archived".to_owned(),
+        ))
+        .into();
+        assert!(matches!(e, Error::PreconditionFailed));
+        let e: Error = RusotoError::<RestoreObjectError>::Unknown(BufferedHttpResponse {
+            status: reqwest::StatusCode::CONFLICT,
+            body: "<Error><Code>RestoreAlreadyInProgress</Code></Error>".into(),
+            headers: Default::default(),
+        })
+        .into();
+        assert!(matches!(e, Error::PreconditionFailed));
+    }
+
+    #[test]
+    fn copy_error_test() {
+        let e: Error = RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
+            "archived".to_owned(),
+        ))
+        .into();
+        assert_eq!(e.io_kind(), Some(ErrorKind::Other));
+    }
+
+    #[test]
+    fn object_acl_error_test() {
+        let e: Error =
+            RusotoError::Service(GetObjectAclError::NoSuchKey("no such key".to_owned())).into();
+        assert_eq!(e.io_kind(), Some(ErrorKind::NotFound));
+        let e: Error =
+            RusotoError::Service(PutObjectAclError::NoSuchKey("no such key".to_owned())).into();
+        assert_eq!(e.io_kind(), Some(ErrorKind::NotFound));
+    }
+}
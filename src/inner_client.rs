@@ -1,11 +1,16 @@
-use std::collections::HashMap;
-
-use crate::{aws::S3Client, errors::Result, types, GetAsBufferResp, PutOrCopyOptions};
+use crate::{
+    aws::S3Client, errors::Result, types, Acl, AppendResp, BucketInfo, ByteRange,
+    CompleteMultipartResp, CompletedPart, CopyResp, CorsRule, CreateBucketOptions, DeleteMultiResp,
+    GetAsBufferResp, GetOptions, GetStreamResp, LifecycleRule, ListVersionsOptions,
+    ListVersionsResp, ObjectMeta, PutOrCopyOptions, PutResp, RestoreOptions,
+};
 use async_trait::async_trait;
-use oss_sdk::OssClient;
+use oss_sdk::{ByteStream, OssClient};
+use std::collections::HashMap;
 
 use crate::{AwosApi, ListDetailsResp, ListOptions};
 
+#[allow(clippy::upper_case_acronyms)]
 pub(crate) enum InnerClient {
     AWS(S3Client),
     OSS(OssClient),
@@ -35,48 +40,102 @@ impl AwosApi for InnerClient {
         }
     }
 
-    async fn get<'a, S, M, F>(&self, key: S, meta_keys_filter: M) -> Result<types::GetResp>
+    async fn list_versions<'a, O>(&self, opts: O) -> Result<ListVersionsResp>
+    where
+        O: Into<Option<ListVersionsOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.list_versions(opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.list_versions(opts).await,
+        }
+    }
+
+    async fn get<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<types::GetResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
         match self {
-            InnerClient::AWS(_s3_client) => _s3_client.get(key, meta_keys_filter).await,
-            InnerClient::OSS(_oss_client) => _oss_client.get(key, meta_keys_filter).await,
+            InnerClient::AWS(_s3_client) => _s3_client.get(key, meta_keys_filter, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get(key, meta_keys_filter, opts).await,
             // _ => unimplemented!(),
         }
     }
 
-    async fn get_as_buffer<'a, S, M, F>(
+    async fn get_as_buffer<'a, S, M, F, O>(
         &self,
         key: S,
         meta_keys_filter: M,
+        opts: O,
     ) -> Result<GetAsBufferResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
         match self {
-            InnerClient::AWS(_s3_client) => _s3_client.get_as_buffer(key, meta_keys_filter).await,
-            InnerClient::OSS(_oss_client) => _oss_client.get_as_buffer(key, meta_keys_filter).await,
-            // _ => unimplemented!(),
+            InnerClient::AWS(_s3_client) => {
+                _s3_client.get_as_buffer(key, meta_keys_filter, opts).await
+            }
+            InnerClient::OSS(_oss_client) => {
+                _oss_client.get_as_buffer(key, meta_keys_filter, opts).await
+            } // _ => unimplemented!(),
         }
     }
 
-    async fn head<S>(&self, key: S) -> Result<HashMap<String, String>>
+    async fn get_stream<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<GetStreamResp>
+    where
+        S: AsRef<str> + Send,
+        M: Into<Option<F>> + Send,
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => {
+                _s3_client.get_stream(key, meta_keys_filter, opts).await
+            }
+            InnerClient::OSS(_oss_client) => {
+                _oss_client.get_stream(key, meta_keys_filter, opts).await
+            }
+        }
+    }
+
+    async fn head<'a, S, V>(&self, key: S, version_id: V) -> Result<ObjectMeta>
     where
         S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
         match self {
-            InnerClient::AWS(_s3_client) => _s3_client.head(key).await,
-            InnerClient::OSS(_oss_client) => _oss_client.head(key).await,
+            InnerClient::AWS(_s3_client) => _s3_client.head(key, version_id).await,
+            InnerClient::OSS(_oss_client) => _oss_client.head(key, version_id).await,
             // _ => unimplemented!(),
         }
     }
 
-    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<()>
+    async fn get_object_meta<S>(&self, key: S) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_object_meta(key).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_object_meta(key).await,
+        }
+    }
+
+    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<PutResp>
     where
         S: AsRef<str> + Send,
         D: Into<Box<[u8]>> + Send,
@@ -89,7 +148,58 @@ impl AwosApi for InnerClient {
         }
     }
 
-    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<()>
+    async fn put_stream<'a, S, O>(&self, key: S, body: ByteStream, opts: O) -> Result<PutResp>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_stream(key, body, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_stream(key, body, opts).await,
+        }
+    }
+
+    async fn append<'a, S, D, O>(
+        &self,
+        key: S,
+        position: u64,
+        data: D,
+        opts: O,
+    ) -> Result<AppendResp>
+    where
+        S: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.append(key, position, data, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.append(key, position, data, opts).await,
+        }
+    }
+
+    async fn put_symlink<'a, S1, S2, O>(&self, key: S1, target: S2, opts: O) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_symlink(key, target, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_symlink(key, target, opts).await,
+        }
+    }
+
+    async fn get_symlink<S>(&self, key: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_symlink(key).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_symlink(key).await,
+        }
+    }
+
+    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<CopyResp>
     where
         S1: Into<String> + Send,
         S2: AsRef<str> + Send,
@@ -102,28 +212,326 @@ impl AwosApi for InnerClient {
         }
     }
 
-    async fn del<S>(&self, key: S) -> Result<()>
+    async fn copy_large<'a, S1, S2, N, O>(
+        &self,
+        src: S1,
+        key: S2,
+        part_size: N,
+        opts: O,
+    ) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.copy_large(src, key, part_size, opts).await,
+            InnerClient::OSS(_oss_client) => {
+                _oss_client.copy_large(src, key, part_size, opts).await
+            }
+        }
+    }
+
+    async fn del<'a, S, V>(&self, key: S, version_id: V) -> Result<()>
     where
         S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
         match self {
-            InnerClient::AWS(_s3_client) => _s3_client.del(key).await,
-            InnerClient::OSS(_oss_client) => _oss_client.del(key).await,
+            InnerClient::AWS(_s3_client) => _s3_client.del(key, version_id).await,
+            InnerClient::OSS(_oss_client) => _oss_client.del(key, version_id).await,
             // _ => unimplemented!(),
         }
     }
 
-    async fn del_multi<S>(&self, keys: &[S]) -> Result<()>
+    async fn del_multi<S>(&self, keys: &[S], quiet: bool) -> Result<DeleteMultiResp>
     where
         S: AsRef<str> + Sync,
     {
         match self {
-            InnerClient::AWS(_s3_client) => _s3_client.del_multi(keys).await,
-            InnerClient::OSS(_oss_client) => _oss_client.del_multi(keys).await,
+            InnerClient::AWS(_s3_client) => _s3_client.del_multi(keys, quiet).await,
+            InnerClient::OSS(_oss_client) => _oss_client.del_multi(keys, quiet).await,
             // _ => unimplemented!(),
         }
     }
 
+    async fn restore<S, O>(&self, key: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<RestoreOptions>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.restore(key, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.restore(key, opts).await,
+        }
+    }
+
+    async fn get_tagging<S>(&self, key: S) -> Result<HashMap<String, String>>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_tagging(key).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_tagging(key).await,
+        }
+    }
+
+    async fn put_tagging<S>(&self, key: S, tags: HashMap<String, String>) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_tagging(key, tags).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_tagging(key, tags).await,
+        }
+    }
+
+    async fn delete_tagging<S>(&self, key: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.delete_tagging(key).await,
+            InnerClient::OSS(_oss_client) => _oss_client.delete_tagging(key).await,
+        }
+    }
+
+    async fn get_object_acl<S>(&self, key: S) -> Result<Acl>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_object_acl(key).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_object_acl(key).await,
+        }
+    }
+
+    async fn put_object_acl<S>(&self, key: S, acl: Acl) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_object_acl(key, acl).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_object_acl(key, acl).await,
+        }
+    }
+
+    async fn get_bucket_acl(&self) -> Result<Acl> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_bucket_acl().await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_bucket_acl().await,
+        }
+    }
+
+    async fn put_bucket_acl(&self, acl: Acl) -> Result<()> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_bucket_acl(acl).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_bucket_acl(acl).await,
+        }
+    }
+
+    async fn create_bucket<'a, S, O>(&self, bucket: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<CreateBucketOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.create_bucket(bucket, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.create_bucket(bucket, opts).await,
+        }
+    }
+
+    async fn delete_bucket<S>(&self, bucket: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.delete_bucket(bucket).await,
+            InnerClient::OSS(_oss_client) => _oss_client.delete_bucket(bucket).await,
+        }
+    }
+
+    async fn list_buckets<'a, P>(&self, prefix: P) -> Result<Vec<BucketInfo>>
+    where
+        P: Into<Option<&'a str>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.list_buckets(prefix).await,
+            InnerClient::OSS(_oss_client) => _oss_client.list_buckets(prefix).await,
+        }
+    }
+
+    async fn bucket_exists<S>(&self, bucket: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.bucket_exists(bucket).await,
+            InnerClient::OSS(_oss_client) => _oss_client.bucket_exists(bucket).await,
+        }
+    }
+
+    async fn get_bucket_info<S>(&self, bucket: S) -> Result<BucketInfo>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_bucket_info(bucket).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_bucket_info(bucket).await,
+        }
+    }
+
+    async fn get_bucket_location<S>(&self, bucket: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_bucket_location(bucket).await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_bucket_location(bucket).await,
+        }
+    }
+
+    async fn get_bucket_lifecycle(&self) -> Result<Vec<LifecycleRule>> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_bucket_lifecycle().await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_bucket_lifecycle().await,
+        }
+    }
+
+    async fn put_bucket_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_bucket_lifecycle(rules).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_bucket_lifecycle(rules).await,
+        }
+    }
+
+    async fn delete_bucket_lifecycle(&self) -> Result<()> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.delete_bucket_lifecycle().await,
+            InnerClient::OSS(_oss_client) => _oss_client.delete_bucket_lifecycle().await,
+        }
+    }
+
+    async fn get_bucket_cors(&self) -> Result<Vec<CorsRule>> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.get_bucket_cors().await,
+            InnerClient::OSS(_oss_client) => _oss_client.get_bucket_cors().await,
+        }
+    }
+
+    async fn put_bucket_cors(&self, rules: Vec<CorsRule>) -> Result<()> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.put_bucket_cors(rules).await,
+            InnerClient::OSS(_oss_client) => _oss_client.put_bucket_cors(rules).await,
+        }
+    }
+
+    async fn delete_bucket_cors(&self) -> Result<()> {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.delete_bucket_cors().await,
+            InnerClient::OSS(_oss_client) => _oss_client.delete_bucket_cors().await,
+        }
+    }
+
+    async fn initiate_multipart<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.initiate_multipart(key, opts).await,
+            InnerClient::OSS(_oss_client) => _oss_client.initiate_multipart(key, opts).await,
+        }
+    }
+
+    async fn upload_part<S1, S2, D>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        data: D,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => {
+                _s3_client
+                    .upload_part(key, upload_id, part_number, data)
+                    .await
+            }
+            InnerClient::OSS(_oss_client) => {
+                _oss_client
+                    .upload_part(key, upload_id, part_number, data)
+                    .await
+            }
+        }
+    }
+
+    async fn upload_part_copy<'a, S1, S2, S3, R, O>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        src: S3,
+        range: R,
+        opts: O,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: Into<String> + Send,
+        R: Into<Option<ByteRange>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => {
+                _s3_client
+                    .upload_part_copy(key, upload_id, part_number, src, range, opts)
+                    .await
+            }
+            InnerClient::OSS(_oss_client) => {
+                _oss_client
+                    .upload_part_copy(key, upload_id, part_number, src, range, opts)
+                    .await
+            }
+        }
+    }
+
+    async fn complete_multipart<S1, S2>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => {
+                _s3_client.complete_multipart(key, upload_id, parts).await
+            }
+            InnerClient::OSS(_oss_client) => {
+                _oss_client.complete_multipart(key, upload_id, parts).await
+            }
+        }
+    }
+
+    async fn abort_multipart<S1, S2>(&self, key: S1, upload_id: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        match self {
+            InnerClient::AWS(_s3_client) => _s3_client.abort_multipart(key, upload_id).await,
+            InnerClient::OSS(_oss_client) => _oss_client.abort_multipart(key, upload_id).await,
+        }
+    }
+
     fn sign_url<'a, S, O>(&self, key: S, opts: O) -> crate::errors::Result<String>
     where
         S: AsRef<str>,
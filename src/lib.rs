@@ -11,7 +11,6 @@ mod oss;
 mod prelude;
 mod types;
 
-use errors::*;
 use oss_sdk::*;
 
 // Api
@@ -20,3 +19,5 @@ pub use awos::*;
 pub use errors::*;
 // Opts
 pub use types::*;
+// Streams
+pub use oss_sdk::ByteStream;
@@ -1,14 +1,347 @@
 use crate::{
+    awos,
     errors::{Error, ParseError},
-    types, AwosApi, GetAsBufferResp, ListDetailsResp, ListOptions, ObjectDetails, PutOrCopyOptions,
-    Result, SignedUrlOptions,
+    types::{self, read_xml_text},
+    Acl, AppendResp, AwosApi, BucketInfo, ByteRange, CompleteMultipartResp, CompletedPart,
+    CopyResp, CorsRule, CreateBucketOptions, DeleteError, DeleteMultiResp, GetAsBufferResp,
+    GetOptions, GetStreamResp, LifecycleRule, LifecycleTime, LifecycleTransition, ListDetailsResp,
+    ListOptions, ListVersionsOptions, ListVersionsResp, ObjectDetails, ObjectMeta, ObjectVersion,
+    Owner, PutOrCopyOptions, PutResp, RestoreOptions, Result, SignedUrlOptions,
 };
 
 use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
 
-use oss_sdk::{OSSClient, SignAndDispatch};
+use oss_sdk::{ByteStream, HttpResponse, OSSClient, SignAndDispatch};
 
-use quick_xml::{events::Event, Reader};
+use quick_xml::{escape::escape, events::Event, Reader};
+
+/// 将 XML 中 tag 的文本解析为 T
+fn parse_text<T: std::str::FromStr>(text: &str, tag: &str) -> Result<T> {
+    text.parse().map_err(|_| {
+        Error::Parse(ParseError::InvalidFormat {
+            msg: format!("Failed parsing content {} from {:?}", tag, text),
+        })
+    })
+}
+
+fn escape_xml(s: &str) -> String {
+    String::from_utf8_lossy(&escape(s.as_bytes())).into_owned()
+}
+
+/// 设置了 x-oss-forbid-overwrite 且目标已存在, 或 append 的 position 不一致时 OSS 返回的错误码, 状态码均为 409。
+const PUT_PRECONDITION_CODES: &[&str] = &["FileAlreadyExists", "PositionNotEqualToLength"];
+
+/// restore 非归档类型的 Object (400), 或解冻正在进行中 (409) 时 OSS 返回的错误码。
+const RESTORE_PRECONDITION_CODES: &[&str] = &["OperationNotSupported", "RestoreAlreadyInProgress"];
+
+/// 读取 OSS 错误响应中的 Code, 如 FileAlreadyExists。
+fn error_code(resp: &HttpResponse) -> Option<String> {
+    let body = std::str::from_utf8(&resp.body).ok()?;
+    read_xml_text(body, b"Code").ok().flatten()
+}
+
+/// 错误码属于 precondition_codes 时返回 PreconditionFailed, 否则按状态码转换。
+fn precondition_error(resp: &HttpResponse, precondition_codes: &[&str]) -> Error {
+    match error_code(resp) {
+        Some(_code) if precondition_codes.contains(&_code.as_str()) => Error::PreconditionFailed,
+        _ => resp.status.as_u16().into(),
+    }
+}
+
+/// put/copy/append 的错误转换
+fn put_error(resp: &HttpResponse) -> Error {
+    precondition_error(resp, PUT_PRECONDITION_CODES)
+}
+
+/// 开启了版本控制时, 写入类请求返回的 x-oss-version-id。
+fn version_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("x-oss-version-id")
+        .and_then(|v| v.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+/// 读取 copy 源 Object 的元数据, 源 Bucket/版本取自 opts。
+async fn head_source<C>(
+    client: &OSSClient<C>,
+    src: &str,
+    opts: &PutOrCopyOptions<'_>,
+) -> Result<ObjectMeta>
+where
+    C: SignAndDispatch + Send + Sync,
+{
+    let mut rqst = client.head_request(src);
+    if let Some(_src_bucket) = opts.src_bucket {
+        rqst.bucket = _src_bucket.to_owned();
+    }
+    if let Some(_version_id) = opts.src_version_id {
+        rqst.add_params("versionId", _version_id);
+    }
+    let resp = client.sign_and_dispatch(rqst).await?;
+    if resp.status.is_success() {
+        let (meta, headers) = types::split_headers(resp.headers);
+        ObjectMeta::from_oss_headers(meta, headers)
+    } else {
+        Err(resp.status.as_u16().into())
+    }
+}
+
+/// 读取 GetObjectACL/GetBucketAcl 返回的 Grant
+async fn get_acl<C>(client: &OSSClient<C>, key: &str) -> Result<Acl>
+where
+    C: SignAndDispatch + Send + Sync,
+{
+    let mut rqst = client.get_request(key);
+    rqst.add_params("acl", None);
+    let resp = client.sign_and_dispatch(rqst).await?;
+    if resp.status.is_success() {
+        read_xml_text(std::str::from_utf8(&resp.body)?, b"Grant")?
+            .ok_or_else(|| {
+                Error::Parse(ParseError::InvalidFormat {
+                    msg: "Grant not found in AccessControlPolicy".to_owned(),
+                })
+            })?
+            .parse()
+    } else {
+        Err(resp.status.as_u16().into())
+    }
+}
+
+/// 解析 ListBuckets/GetBucketInfo 返回中的 Bucket, 追加到 result 中。
+/// 被截断时返回用于请求下一页的 NextMarker。
+fn parse_buckets(content: &str, result: &mut Vec<BucketInfo>) -> Result<Option<String>> {
+    let mut reader = Reader::from_str(content);
+    let mut buf = Vec::new();
+    let mut cur_bucket = BucketInfo::default();
+    let mut owner: Option<Owner> = None;
+    let mut is_truncated = false;
+    let mut next_marker = None;
+    let start = result.len();
+    reader.trim_text(true);
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) => match e.name() {
+                b"Name" => cur_bucket.name = reader.read_text(e.name(), &mut Vec::new())?,
+                b"Location" => cur_bucket.location = reader.read_text(e.name(), &mut Vec::new())?,
+                b"CreationDate" => {
+                    let text = reader.read_text(e.name(), &mut Vec::new())?;
+                    cur_bucket.creation_date = Some(types::parse_iso8601(&text)?)
+                }
+                b"StorageClass" => {
+                    cur_bucket.storage_class = reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"Grant" => {
+                    cur_bucket.acl = Some(reader.read_text(e.name(), &mut Vec::new())?.parse()?)
+                }
+                b"ID" => {
+                    owner.get_or_insert_with(Default::default).id =
+                        reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"DisplayName" => {
+                    owner.get_or_insert_with(Default::default).display_name =
+                        reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"IsTruncated" => {
+                    let text = reader.read_text(e.name(), &mut Vec::new())?;
+                    is_truncated = parse_text(&text, "IsTruncated")?
+                }
+                b"NextMarker" => next_marker = Some(reader.read_text(e.name(), &mut Vec::new())?),
+                _ => (),
+            },
+            Event::End(ref e) if e.name() == b"Bucket" => {
+                result.push(std::mem::take(&mut cur_bucket));
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+    // ListBuckets 的 Owner 在 Bucket 之外, 对所有 Bucket 相同。
+    for bucket in result[start..].iter_mut() {
+        bucket.owner = owner.clone();
+    }
+    Ok(next_marker.filter(|_| is_truncated))
+}
+
+/// 生命周期规则中的时间条件, 对应 Days/CreatedBeforeDate
+fn lifecycle_time_xml(time: LifecycleTime) -> String {
+    match time {
+        LifecycleTime::Days(_days) => format!("<Days>{}</Days>", _days),
+        LifecycleTime::Date(_date) => format!(
+            "<CreatedBeforeDate>{}</CreatedBeforeDate>",
+            types::lifecycle_date(_date)
+        ),
+    }
+}
+
+/// 解析 GetBucketLifecycle 的返回
+/// 只读取 Rule 或 Rule/Filter 下的 Prefix, 以及 Rule 下的 Tag, 忽略 Filter/Not 等其他位置的同名元素。
+fn parse_lifecycle(content: &str) -> Result<Vec<LifecycleRule>> {
+    let mut reader = Reader::from_str(content);
+    let mut buf = Vec::new();
+    let mut rules = Vec::new();
+    let mut cur_rule = LifecycleRule::default();
+    let mut cur_key = String::new();
+    let mut cur_time = None;
+    let mut cur_storage_class = None;
+    // 当前所在的元素路径, 不含由 read_text 读取的叶子元素
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    let in_rule = |path: &[Vec<u8>]| path.last().is_some_and(|_name| _name == b"Rule");
+    let in_rule_filter = |path: &[Vec<u8>]| {
+        path.len() >= 2 && path[path.len() - 2] == b"Rule" && path[path.len() - 1] == b"Filter"
+    };
+    let in_rule_tag = |path: &[Vec<u8>]| {
+        path.len() >= 2 && path[path.len() - 2] == b"Rule" && path[path.len() - 1] == b"Tag"
+    };
+    reader.trim_text(true);
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) => match e.name() {
+                b"ID" if in_rule(&path) => {
+                    cur_rule.id = reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"Prefix" if in_rule(&path) || in_rule_filter(&path) => {
+                    cur_rule.prefix = reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"Status" if in_rule(&path) => {
+                    cur_rule.enabled = reader.read_text(e.name(), &mut Vec::new())? == "Enabled"
+                }
+                b"Key" if in_rule_tag(&path) => {
+                    cur_key = reader.read_text(e.name(), &mut Vec::new())?
+                }
+                b"Value" if in_rule_tag(&path) => {
+                    let value = reader.read_text(e.name(), &mut Vec::new())?;
+                    cur_rule.tags.insert(std::mem::take(&mut cur_key), value);
+                }
+                b"Days" => {
+                    let text = reader.read_text(e.name(), &mut Vec::new())?;
+                    cur_time = Some(LifecycleTime::Days(parse_text(&text, "Days")?))
+                }
+                b"CreatedBeforeDate" => {
+                    let text = reader.read_text(e.name(), &mut Vec::new())?;
+                    cur_time = Some(LifecycleTime::Date(types::parse_iso8601(&text)?))
+                }
+                b"StorageClass" => {
+                    cur_storage_class = Some(reader.read_text(e.name(), &mut Vec::new())?.parse()?)
+                }
+                _name => {
+                    // 每个条件中的 Days/CreatedBeforeDate/StorageClass 在其结束时读取
+                    if matches!(
+                        _name,
+                        b"Expiration" | b"Transition" | b"AbortMultipartUpload"
+                    ) {
+                        cur_time = None;
+                        cur_storage_class = None;
+                    }
+                    path.push(_name.to_vec());
+                }
+            },
+            Event::Empty(ref e) if e.name() == b"Value" && in_rule_tag(&path) => {
+                cur_rule
+                    .tags
+                    .insert(std::mem::take(&mut cur_key), String::new());
+            }
+            Event::End(ref e) => {
+                path.pop();
+                match e.name() {
+                    b"Expiration" if in_rule(&path) => cur_rule.expiration = cur_time.take(),
+                    b"Transition" if in_rule(&path) => {
+                        if let (Some(time), Some(storage_class)) =
+                            (cur_time.take(), cur_storage_class.take())
+                        {
+                            cur_rule.transitions.push(LifecycleTransition {
+                                time,
+                                storage_class,
+                            });
+                        }
+                    }
+                    b"AbortMultipartUpload" if in_rule(&path) => {
+                        if let Some(LifecycleTime::Days(_days)) = cur_time.take() {
+                            cur_rule.abort_multipart_days = Some(_days);
+                        }
+                    }
+                    b"Rule" => rules.push(std::mem::take(&mut cur_rule)),
+                    _ => (),
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(rules)
+}
+
+/// 解析 GetBucketCors 的返回
+fn parse_cors(content: &str) -> Result<Vec<CorsRule>> {
+    let mut reader = Reader::from_str(content);
+    let mut buf = Vec::new();
+    let mut rules = Vec::new();
+    let mut cur_rule = CorsRule::default();
+    reader.trim_text(true);
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) => match e.name() {
+                b"AllowedOrigin" => cur_rule
+                    .allowed_origins
+                    .push(reader.read_text(e.name(), &mut Vec::new())?),
+                b"AllowedMethod" => cur_rule
+                    .allowed_methods
+                    .push(reader.read_text(e.name(), &mut Vec::new())?),
+                b"AllowedHeader" => cur_rule
+                    .allowed_headers
+                    .push(reader.read_text(e.name(), &mut Vec::new())?),
+                b"ExposeHeader" => cur_rule
+                    .expose_headers
+                    .push(reader.read_text(e.name(), &mut Vec::new())?),
+                b"MaxAgeSeconds" => {
+                    let text = reader.read_text(e.name(), &mut Vec::new())?;
+                    cur_rule.max_age_seconds = Some(parse_text(&text, "MaxAgeSeconds")?)
+                }
+                _ => (),
+            },
+            Event::End(ref e) if e.name() == b"CORSRule" => {
+                rules.push(std::mem::take(&mut cur_rule))
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+    Ok(rules)
+}
+
+/// 解析 DeleteMultipleObjects 的返回, 追加到 result 中
+fn parse_delete_result(content: &str, result: &mut DeleteMultiResp) -> Result<()> {
+    let mut reader = Reader::from_str(content);
+    let mut buf = Vec::new();
+    let mut cur_err: Option<DeleteError> = None;
+    reader.trim_text(true);
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) => match (e.name(), cur_err.as_mut()) {
+                (b"Error", _) => cur_err = Some(DeleteError::default()),
+                (b"Key", Some(_err)) => _err.key = reader.read_text(e.name(), &mut Vec::new())?,
+                (b"Key", None) => result
+                    .deleted
+                    .push(reader.read_text(e.name(), &mut Vec::new())?),
+                (b"Code", Some(_err)) => _err.code = reader.read_text(e.name(), &mut Vec::new())?,
+                (b"Message", Some(_err)) => {
+                    _err.message = reader.read_text(e.name(), &mut Vec::new())?
+                }
+                _ => (),
+            },
+            Event::End(ref e) if e.name() == b"Error" => {
+                result.errors.extend(cur_err.take());
+            }
+            Event::Eof => return Ok(()),
+            _ => (),
+        }
+        buf.clear();
+    }
+}
 
 #[async_trait]
 impl<C: SignAndDispatch + Send + Sync> AwosApi for OSSClient<C> {
@@ -36,43 +369,172 @@ impl<C: SignAndDispatch + Send + Sync> AwosApi for OSSClient<C> {
             let mut buf = Vec::new();
             let mut result = ListDetailsResp::default();
             let mut cur_obj = ObjectDetails::default();
+            let mut in_common_prefixes = false;
             reader.trim_text(true);
             loop {
                 match reader.read_event(&mut buf) {
                     Ok(Event::Start(ref e)) => match e.name() {
                         b"Contents" => {}
+                        b"CommonPrefixes" => in_common_prefixes = true,
                         b"Key" => cur_obj.key = reader.read_text(e.name(), &mut Vec::new())?,
                         b"LastModified" => {
-                            cur_obj.last_modified = reader.read_text(e.name(), &mut Vec::new())?
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_obj.last_modified = types::parse_iso8601(&text)?
+                        }
+                        b"ETag" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_obj.e_tag = types::trim_etag(&text).to_owned()
+                        }
+                        b"Size" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_obj.size = parse_text(&text, "Size")?
+                        }
+                        b"StorageClass" => {
+                            cur_obj.storage_class = reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"Type" => {
+                            cur_obj.object_type = reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"ID" => {
+                            cur_obj.owner.get_or_insert_with(Default::default).id =
+                                reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"DisplayName" => {
+                            cur_obj
+                                .owner
+                                .get_or_insert_with(Default::default)
+                                .display_name = reader.read_text(e.name(), &mut Vec::new())?
                         }
-                        b"ETag" => cur_obj.e_tag = reader.read_text(e.name(), &mut Vec::new())?,
-                        b"Size" => cur_obj.size = reader.read_text(e.name(), &mut Vec::new())?,
                         b"IsTruncated" => {
-                            result.is_truncated = reader
-                                .read_text(e.name(), &mut Vec::new())?
-                                .parse()
-                                .map_err(|_| {
-                                    Error::Parse(ParseError::InvalidFormat {
-                                        msg: format!("Failed parsing content IsTruncated to bool"),
-                                    })
-                                })?
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            result.is_truncated = parse_text(&text, "IsTruncated")?
                         }
-                        b"NextContinuationToken" => {
+                        b"NextMarker" => {
                             result.next_marker = reader.read_text(e.name(), &mut Vec::new())?
                         }
-                        b"Prefixes" => {
-                            result.prefix = reader.read_text(e.name(), &mut Vec::new())?
+                        b"ContinuationToken" => {
+                            result.continuation_token =
+                                reader.read_text(e.name(), &mut Vec::new())?
                         }
+                        b"NextContinuationToken" => {
+                            result.next_continuation_token =
+                                reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"KeyCount" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            result.key_count = parse_text(&text, "KeyCount")?
+                        }
+                        b"Prefix" if in_common_prefixes => result
+                            .common_prefixes
+                            .push(reader.read_text(e.name(), &mut Vec::new())?),
+                        b"Prefix" => result.prefix = reader.read_text(e.name(), &mut Vec::new())?,
                         _ => (),
                     },
-                    Ok(Event::End(ref e)) => match e.name() {
-                        b"Contents" => {
-                            result.objects.push(std::mem::take(&mut cur_obj));
+                    Ok(Event::End(ref e)) if e.name() == b"Contents" => {
+                        result.objects.push(std::mem::take(&mut cur_obj));
+                    }
+                    Ok(Event::End(ref e)) if e.name() == b"CommonPrefixes" => {
+                        in_common_prefixes = false;
+                    }
+                    Ok(Event::Eof) => break,
+                    Err(e) => return Err(e.into()),
+                    _ => (),
+                }
+                buf.clear();
+            }
+            Ok(result)
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+    async fn list_versions<'a, O>(&self, opts: O) -> Result<ListVersionsResp>
+    where
+        O: Into<Option<ListVersionsOptions<'a>>> + Send,
+    {
+        let mut rqst = self.get_request(None);
+        rqst.set_params(
+            opts.into()
+                .unwrap_or_default()
+                .to_params()
+                .into_iter()
+                .collect(),
+        );
+        let resp = self.sign_and_dispatch(rqst).await?;
+
+        if resp.status.is_success() {
+            let resp_content = std::str::from_utf8(&resp.body)?;
+            let mut reader = Reader::from_str(resp_content);
+            let mut buf = Vec::new();
+            let mut result = ListVersionsResp::default();
+            // DeleteMarker 的字段是 Version 的子集, 先按 Version 读取, 结束时再转换。
+            let mut cur_version = ObjectVersion::default();
+            let mut in_common_prefixes = false;
+            reader.trim_text(true);
+            loop {
+                match reader.read_event(&mut buf) {
+                    Ok(Event::Start(ref e)) => match e.name() {
+                        b"CommonPrefixes" => in_common_prefixes = true,
+                        b"Key" => cur_version.key = reader.read_text(e.name(), &mut Vec::new())?,
+                        b"VersionId" => {
+                            cur_version.version_id = reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"IsLatest" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_version.is_latest = parse_text(&text, "IsLatest")?
+                        }
+                        b"LastModified" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_version.last_modified = types::parse_iso8601(&text)?
+                        }
+                        b"ETag" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_version.e_tag = types::trim_etag(&text).to_owned()
+                        }
+                        b"Size" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            cur_version.size = parse_text(&text, "Size")?
+                        }
+                        b"StorageClass" => {
+                            cur_version.storage_class =
+                                reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"ID" => {
+                            cur_version.owner.get_or_insert_with(Default::default).id =
+                                reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"DisplayName" => {
+                            cur_version
+                                .owner
+                                .get_or_insert_with(Default::default)
+                                .display_name = reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"IsTruncated" => {
+                            let text = reader.read_text(e.name(), &mut Vec::new())?;
+                            result.is_truncated = parse_text(&text, "IsTruncated")?
+                        }
+                        b"NextKeyMarker" => {
+                            result.next_key_marker = reader.read_text(e.name(), &mut Vec::new())?
                         }
+                        b"NextVersionIdMarker" => {
+                            result.next_version_id_marker =
+                                reader.read_text(e.name(), &mut Vec::new())?
+                        }
+                        b"Prefix" if in_common_prefixes => result
+                            .common_prefixes
+                            .push(reader.read_text(e.name(), &mut Vec::new())?),
+                        b"Prefix" => result.prefix = reader.read_text(e.name(), &mut Vec::new())?,
+                        _ => (),
+                    },
+                    Ok(Event::End(ref e)) => match e.name() {
+                        b"Version" => result.versions.push(std::mem::take(&mut cur_version)),
+                        b"DeleteMarker" => result
+                            .delete_markers
+                            .push(std::mem::take(&mut cur_version).into()),
+                        b"CommonPrefixes" => in_common_prefixes = false,
                         _ => (),
                     },
                     Ok(Event::Eof) => break,
-                    Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                    Err(e) => return Err(e.into()),
                     _ => (),
                 }
                 buf.clear();
@@ -82,26 +544,43 @@ impl<C: SignAndDispatch + Send + Sync> AwosApi for OSSClient<C> {
             Err(resp.status.as_u16().into())
         }
     }
-    async fn get<'a, S, M, F>(&self, key: S, meta_keys_filter: M) -> Result<types::GetResp>
+    async fn get<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<types::GetResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        Ok(self.get_as_buffer(key, meta_keys_filter).await?.into())
+        Ok(self
+            .get_as_buffer(key, meta_keys_filter, opts)
+            .await?
+            .into())
     }
 
-    async fn get_as_buffer<'a, S, M, F>(
+    async fn get_as_buffer<'a, S, M, F, O>(
         &self,
         key: S,
         meta_keys_filter: M,
+        opts: O,
     ) -> Result<GetAsBufferResp>
     where
         S: AsRef<str> + Send,
         M: Into<Option<F>> + Send,
         F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
     {
-        let rqst = self.get_request(key.as_ref());
+        let mut rqst = self.get_request(key.as_ref());
+        if let Some(_opts) = opts.into() {
+            rqst.add_headers(_opts.as_headers());
+            if let Some(_version_id) = _opts.version_id.as_deref() {
+                rqst.add_params("versionId", _version_id);
+            }
+        }
         let resp = self.sign_and_dispatch(rqst).await?;
         if resp.status.is_success() {
             let mut get_resp: GetAsBufferResp = resp.into();
@@ -114,61 +593,267 @@ impl<C: SignAndDispatch + Send + Sync> AwosApi for OSSClient<C> {
             Err(resp.status.as_u16().into())
         }
     }
-    async fn head<S>(&self, key: S) -> Result<std::collections::HashMap<String, String>>
+    async fn get_stream<'a, S, M, F, O>(
+        &self,
+        key: S,
+        meta_keys_filter: M,
+        opts: O,
+    ) -> Result<GetStreamResp>
+    where
+        S: AsRef<str> + Send,
+        M: Into<Option<F>> + Send,
+        F: IntoIterator<Item = &'a str> + Send,
+        O: Into<Option<GetOptions>> + Send,
+    {
+        let mut rqst = self.get_request(key.as_ref());
+        if let Some(_opts) = opts.into() {
+            rqst.add_headers(_opts.as_headers());
+            if let Some(_version_id) = _opts.version_id.as_deref() {
+                rqst.add_params("versionId", _version_id);
+            }
+        }
+        let resp = self.sign_and_dispatch_streaming(rqst).await?;
+        if resp.status.is_success() {
+            let mut get_resp: GetStreamResp = resp.into();
+            if let Some(_meta_keys_filter) = meta_keys_filter.into() {
+                let _filter = _meta_keys_filter.into_iter().collect();
+                get_resp.filter(_filter);
+            }
+            Ok(get_resp)
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn head<'a, S, V>(&self, key: S, version_id: V) -> Result<ObjectMeta>
+    where
+        S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
+    {
+        let mut rqst = self.head_request(key.as_ref());
+        if let Some(_version_id) = version_id.into() {
+            rqst.add_params("versionId", _version_id);
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let (meta, headers) = types::split_headers(resp.headers);
+            ObjectMeta::from_oss_headers(meta, headers)
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_object_meta<S>(&self, key: S) -> Result<ObjectMeta>
     where
         S: AsRef<str> + Send,
     {
-        let mut resp = self.get_as_buffer::<_, _, Vec<_>>(key, None).await?;
-        resp.headers.extend(resp.meta.into_iter());
-        Ok(resp.headers)
+        let mut rqst = self.head_request(key.as_ref());
+        rqst.add_params("objectMeta", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let (meta, headers) = types::split_headers(resp.headers);
+            ObjectMeta::from_oss_headers(meta, headers)
+        } else {
+            Err(resp.status.as_u16().into())
+        }
     }
 
-    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<()>
+    async fn put<'a, S, D, O>(&self, key: S, data: D, opts: O) -> Result<PutResp>
     where
         S: AsRef<str> + Send,
         D: Into<Box<[u8]>> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send,
     {
-        let mut rqst = self.put_request(key.as_ref(), data.into());
+        let key = key.as_ref();
+        let mut rqst = self.put_request(key, data.into());
         if let Some(_opts) = opts.into() {
+            _opts.check_put_conditions(false)?;
             rqst.add_headers(_opts.as_headers());
             rqst.add_meta(_opts.meta.unwrap_or_default());
         }
         let resp = self.sign_and_dispatch(rqst).await?;
         if resp.status.is_success() {
-            Ok(())
+            Ok(PutResp::from_oss_headers(&resp.headers))
         } else {
-            Err(resp.status.as_u16().into())
+            Err(put_error(&resp))
         }
     }
 
-    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<()>
+    async fn put_stream<'a, S, O>(&self, key: S, body: ByteStream, opts: O) -> Result<PutResp>
     where
-        S1: Into<String> + Send,
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let key = key.as_ref();
+        let mut rqst = self.put_stream_request(key, body);
+        if let Some(_opts) = opts.into() {
+            _opts.check_put_conditions(false)?;
+            rqst.add_headers(_opts.as_headers());
+            rqst.add_meta(_opts.meta.unwrap_or_default());
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(PutResp::from_oss_headers(&resp.headers))
+        } else {
+            Err(put_error(&resp))
+        }
+    }
+
+    async fn append<'a, S, D, O>(
+        &self,
+        key: S,
+        position: u64,
+        data: D,
+        opts: O,
+    ) -> Result<AppendResp>
+    where
+        S: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let mut rqst = self.post_request(key.as_ref(), data.into());
+        rqst.add_params("append", None);
+        rqst.add_params("position", position.to_string().as_str());
+        if let Some(_opts) = opts.into() {
+            rqst.add_headers(_opts.as_headers());
+            rqst.add_meta(_opts.meta.unwrap_or_default());
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let header = |name: &str| resp.headers.get(name).and_then(|v| v.to_str().ok());
+            let next_position = header("x-oss-next-append-position").ok_or_else(|| {
+                Error::Parse(ParseError::InvalidFormat {
+                    msg: "x-oss-next-append-position not found in response".to_owned(),
+                })
+            })?;
+            Ok(AppendResp {
+                next_position: parse_text(next_position, "x-oss-next-append-position")?,
+                crc64: header("x-oss-hash-crc64ecma")
+                    .map(|_crc64| parse_text(_crc64, "x-oss-hash-crc64ecma"))
+                    .transpose()?,
+            })
+        } else {
+            Err(put_error(&resp))
+        }
+    }
+
+    async fn put_symlink<'a, S1, S2, O>(&self, key: S1, target: S2, opts: O) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         O: Into<Option<PutOrCopyOptions<'a>>> + Send,
     {
         let mut rqst = self.put_request(key.as_ref(), None);
+        rqst.add_params("symlink", None);
+        rqst.add_headers(Some((
+            "x-oss-symlink-target",
+            types::encode_uri(target.as_ref(), true),
+        )));
         if let Some(_opts) = opts.into() {
-            let mut headers = _opts.as_headers();
-            let src = src.into();
-            headers.insert("x-oss-copy-source", &src);
-            rqst.add_headers(headers);
+            _opts.check_put_conditions(false)?;
+            rqst.add_headers(_opts.as_headers());
             rqst.add_meta(_opts.meta.unwrap_or_default());
         }
         let resp = self.sign_and_dispatch(rqst).await?;
         if resp.status.is_success() {
             Ok(())
+        } else {
+            Err(put_error(&resp))
+        }
+    }
+
+    async fn get_symlink<S>(&self, key: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.get_request(key.as_ref());
+        rqst.add_params("symlink", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let target = resp
+                .headers
+                .get("x-oss-symlink-target")
+                .and_then(|_target| _target.to_str().ok())
+                .ok_or_else(|| {
+                    Error::Parse(ParseError::InvalidFormat {
+                        msg: "x-oss-symlink-target not found in response".to_owned(),
+                    })
+                })?;
+            types::decode_uri(target)
         } else {
             Err(resp.status.as_u16().into())
         }
     }
 
-    async fn del<S>(&self, key: S) -> Result<()>
+    async fn copy<'a, S1, S2, O>(&self, src: S1, key: S2, opts: O) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let key = key.as_ref();
+        let opts = opts.into().unwrap_or_default();
+        opts.check_put_conditions(false)?;
+        let copy_source = types::copy_source(
+            opts.src_bucket.unwrap_or_else(|| self.get_bucket()),
+            &src.into(),
+            opts.src_version_id,
+        );
+        let mut rqst = self.put_request(key, None);
+        rqst.add_headers(opts.as_headers());
+        rqst.add_headers(vec![
+            ("x-oss-copy-source", format!("/{}", copy_source)),
+            (
+                "x-oss-metadata-directive",
+                opts.metadata_directive().to_string(),
+            ),
+        ]);
+        if opts.tags.is_some() {
+            rqst.add_headers(Some(("x-oss-tagging-directive", "Replace")));
+        }
+        rqst.add_meta(opts.meta.unwrap_or_default());
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let e_tag = read_xml_text(std::str::from_utf8(&resp.body)?, b"ETag")?;
+            Ok(CopyResp {
+                e_tag: types::trim_etag(&e_tag.unwrap_or_default()).to_owned(),
+                version_id: version_id(&resp.headers),
+            })
+        } else {
+            Err(put_error(&resp))
+        }
+    }
+
+    async fn copy_large<'a, S1, S2, N, O>(
+        &self,
+        src: S1,
+        key: S2,
+        part_size: N,
+        opts: O,
+    ) -> Result<CopyResp>
+    where
+        S1: Into<String> + Send,
+        S2: AsRef<str> + Send,
+        N: Into<Option<usize>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let (src, key) = (src.into(), key.as_ref());
+        let opts = opts.into().unwrap_or_default();
+        opts.check_put_conditions(true)?;
+        opts.check_multipart_encryption()?;
+        let src_meta = head_source(self, &src, &opts).await?;
+        awos::copy_multipart(self, &src, key, src_meta, part_size.into(), opts).await
+    }
+
+    async fn del<'a, S, V>(&self, key: S, version_id: V) -> Result<()>
     where
         S: AsRef<str> + Send,
+        V: Into<Option<&'a str>> + Send,
     {
-        let rqst = self.del_request(key.as_ref());
+        let mut rqst = self.del_request(key.as_ref());
+        if let Some(_version_id) = version_id.into() {
+            rqst.add_params("versionId", _version_id);
+        }
         let resp = self.sign_and_dispatch(rqst).await?;
         if resp.status.is_success() {
             Ok(())
@@ -177,25 +862,792 @@ impl<C: SignAndDispatch + Send + Sync> AwosApi for OSSClient<C> {
         }
     }
 
-    async fn del_multi<S>(&self, keys: &[S]) -> Result<()>
+    async fn del_multi<S>(&self, keys: &[S], quiet: bool) -> Result<DeleteMultiResp>
     where
         S: AsRef<str> + Sync,
     {
-        for key in keys {
-            self.del(key.as_ref()).await?;
+        let mut ret = DeleteMultiResp::default();
+        for chunk in keys.chunks(types::MAX_DELETE_KEYS) {
+            let mut body = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><Delete><Quiet>{}</Quiet>"#,
+                quiet
+            );
+            for key in chunk {
+                body += &format!("<Object><Key>{}</Key></Object>", escape_xml(key.as_ref()));
+            }
+            body += "</Delete>";
+            let mut rqst = self.post_request("", body.into_bytes().into_boxed_slice());
+            rqst.add_params("delete", None);
+            rqst.maybe_set_content_md5_header();
+            let resp = self.sign_and_dispatch(rqst).await?;
+            if resp.status.is_success() {
+                parse_delete_result(std::str::from_utf8(&resp.body)?, &mut ret)?;
+            } else {
+                return Err(resp.status.as_u16().into());
+            }
         }
-        Ok(())
+        Ok(ret)
     }
 
-    // AWOS-JS 貌似省略了 content-type 和 content-md5。 这里也先省略
-    fn sign_url<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    async fn restore<S, O>(&self, key: S, opts: O) -> Result<()>
     where
-        S: AsRef<str>,
-        O: Into<Option<SignedUrlOptions<'a>>>,
+        S: AsRef<str> + Send,
+        O: Into<Option<RestoreOptions>> + Send,
     {
         let opts = opts.into().unwrap_or_default();
-        let expires = opts.expires.unwrap_or(3600);
-        let method = opts.method.unwrap_or("GET");
-        Ok(self.get_signed_url(key.as_ref(), method, expires, "", None))
+        let mut body = String::from("<RestoreRequest>");
+        body += &format!("<Days>{}</Days>", opts.days.unwrap_or(1));
+        if let Some(_tier) = opts.tier {
+            body += &format!("<JobParameters><Tier>{}</Tier></JobParameters>", _tier);
+        }
+        body += "</RestoreRequest>";
+        let mut rqst = self.post_request(key.as_ref(), body.into_bytes().into_boxed_slice());
+        rqst.add_params("restore", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(precondition_error(&resp, RESTORE_PRECONDITION_CODES))
+        }
+    }
+
+    async fn get_tagging<S>(&self, key: S) -> Result<HashMap<String, String>>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.get_request(key.as_ref());
+        rqst.add_params("tagging", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let mut reader = Reader::from_str(std::str::from_utf8(&resp.body)?);
+            let mut buf = Vec::new();
+            let mut tags = HashMap::new();
+            let mut cur_key = String::new();
+            reader.trim_text(true);
+            loop {
+                match reader.read_event(&mut buf)? {
+                    Event::Start(ref e) => match e.name() {
+                        b"Key" => cur_key = reader.read_text(e.name(), &mut Vec::new())?,
+                        b"Value" => {
+                            let value = reader.read_text(e.name(), &mut Vec::new())?;
+                            tags.insert(std::mem::take(&mut cur_key), value);
+                        }
+                        _ => (),
+                    },
+                    // 空的 Value 会被解析为 Empty
+                    Event::Empty(ref e) if e.name() == b"Value" => {
+                        tags.insert(std::mem::take(&mut cur_key), String::new());
+                    }
+                    Event::Eof => break,
+                    _ => (),
+                }
+                buf.clear();
+            }
+            Ok(tags)
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn put_tagging<S>(&self, key: S, tags: HashMap<String, String>) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut body = String::from("<Tagging><TagSet>");
+        for (k, v) in tags {
+            body += &format!(
+                "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+                escape_xml(&k),
+                escape_xml(&v)
+            );
+        }
+        body += "</TagSet></Tagging>";
+        let mut rqst = self.put_request(key.as_ref(), body.into_bytes().into_boxed_slice());
+        rqst.add_params("tagging", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn delete_tagging<S>(&self, key: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.del_request(key.as_ref());
+        rqst.add_params("tagging", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_object_acl<S>(&self, key: S) -> Result<Acl>
+    where
+        S: AsRef<str> + Send,
+    {
+        get_acl(self, key.as_ref()).await
+    }
+
+    async fn put_object_acl<S>(&self, key: S, acl: Acl) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.put_request(key.as_ref(), None);
+        rqst.add_params("acl", None);
+        rqst.add_headers(Some(("x-oss-object-acl", acl.to_string())));
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_bucket_acl(&self) -> Result<Acl> {
+        get_acl(self, "").await
+    }
+
+    async fn put_bucket_acl(&self, acl: Acl) -> Result<()> {
+        types::check_bucket_acl(acl)?;
+        let mut rqst = self.put_request("", None);
+        rqst.add_params("acl", None);
+        rqst.add_headers(Some(("x-oss-acl", acl.to_string())));
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn create_bucket<'a, S, O>(&self, bucket: S, opts: O) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<CreateBucketOptions<'a>>> + Send,
+    {
+        let opts = opts.into().unwrap_or_default();
+        let body = opts.storage_class.map(|_class| {
+            format!(
+                "<CreateBucketConfiguration><StorageClass>{}</StorageClass></CreateBucketConfiguration>",
+                _class.as_oss_str()
+            )
+            .into_bytes()
+            .into_boxed_slice()
+        });
+        let mut rqst = self.put_request("", body);
+        rqst.bucket = bucket.as_ref().to_owned();
+        if let Some(_region) = opts.region {
+            rqst.region = _region.parse().map_err(|e: oss_sdk::ParseRegionError| {
+                Error::Parse(ParseError::InvalidFormat { msg: e.to_string() })
+            })?;
+        }
+        if let Some(_acl) = opts.acl {
+            types::check_bucket_acl(_acl)?;
+            rqst.add_headers(Some(("x-oss-acl", _acl.to_string())));
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(precondition_error(&resp, &["BucketAlreadyExists"]))
+        }
+    }
+
+    async fn delete_bucket<S>(&self, bucket: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.del_request("");
+        rqst.bucket = bucket.as_ref().to_owned();
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn list_buckets<'a, P>(&self, prefix: P) -> Result<Vec<BucketInfo>>
+    where
+        P: Into<Option<&'a str>> + Send,
+    {
+        let prefix = prefix.into();
+        let mut result = Vec::new();
+        let mut marker = None;
+        loop {
+            // Bucket 为空时请求的是 Service 级别的 GetService (ListBuckets)。
+            let mut rqst = self.get_request(None);
+            rqst.bucket = String::new();
+            if let Some(_prefix) = prefix {
+                rqst.add_params("prefix", _prefix);
+            }
+            if let Some(_marker) = marker.as_deref() {
+                rqst.add_params("marker", _marker);
+            }
+            rqst.add_params("max-keys", "1000");
+            let resp = self.sign_and_dispatch(rqst).await?;
+            if !resp.status.is_success() {
+                return Err(resp.status.as_u16().into());
+            }
+            marker = parse_buckets(std::str::from_utf8(&resp.body)?, &mut result)?;
+            if marker.is_none() {
+                return Ok(result);
+            }
+        }
+    }
+
+    async fn bucket_exists<S>(&self, bucket: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.get_request(None);
+        rqst.bucket = bucket.as_ref().to_owned();
+        rqst.add_params("bucketInfo", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        match resp.status.as_u16() {
+            404 => Ok(false),
+            _ if resp.status.is_success() => Ok(true),
+            _status => Err(_status.into()),
+        }
+    }
+
+    async fn get_bucket_info<S>(&self, bucket: S) -> Result<BucketInfo>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.get_request(None);
+        rqst.bucket = bucket.as_ref().to_owned();
+        rqst.add_params("bucketInfo", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let mut result = Vec::with_capacity(1);
+            parse_buckets(std::str::from_utf8(&resp.body)?, &mut result)?;
+            result.pop().ok_or_else(|| {
+                Error::Parse(ParseError::InvalidFormat {
+                    msg: "Bucket not found in BucketInfo".to_owned(),
+                })
+            })
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_bucket_location<S>(&self, bucket: S) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut rqst = self.get_request(None);
+        rqst.bucket = bucket.as_ref().to_owned();
+        rqst.add_params("location", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            read_xml_text(std::str::from_utf8(&resp.body)?, b"LocationConstraint")?.ok_or_else(
+                || {
+                    Error::Parse(ParseError::InvalidFormat {
+                        msg: "LocationConstraint not found in response".to_owned(),
+                    })
+                },
+            )
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_bucket_lifecycle(&self) -> Result<Vec<LifecycleRule>> {
+        let mut rqst = self.get_request(None);
+        rqst.add_params("lifecycle", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        let content = std::str::from_utf8(&resp.body)?;
+        if resp.status.is_success() {
+            parse_lifecycle(content)
+        } else if resp.status.as_u16() == 404 && content.contains("NoSuchLifecycle") {
+            Ok(Vec::new())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn put_bucket_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        let mut body =
+            String::from(r#"<?xml version="1.0" encoding="UTF-8"?><LifecycleConfiguration>"#);
+        for rule in rules {
+            body += "<Rule>";
+            if !rule.id.is_empty() {
+                body += &format!("<ID>{}</ID>", escape_xml(&rule.id));
+            }
+            body += &format!("<Prefix>{}</Prefix>", escape_xml(&rule.prefix));
+            for (k, v) in rule.tags.iter() {
+                body += &format!(
+                    "<Tag><Key>{}</Key><Value>{}</Value></Tag>",
+                    escape_xml(k),
+                    escape_xml(v)
+                );
+            }
+            body += &format!(
+                "<Status>{}</Status>",
+                if rule.enabled { "Enabled" } else { "Disabled" }
+            );
+            if let Some(_expiration) = rule.expiration {
+                body += &format!(
+                    "<Expiration>{}</Expiration>",
+                    lifecycle_time_xml(_expiration)
+                );
+            }
+            for _transition in rule.transitions.iter() {
+                body += &format!(
+                    "<Transition>{}<StorageClass>{}</StorageClass></Transition>",
+                    lifecycle_time_xml(_transition.time),
+                    _transition.storage_class.as_oss_str()
+                );
+            }
+            if let Some(_days) = rule.abort_multipart_days {
+                body += &format!(
+                    "<AbortMultipartUpload><Days>{}</Days></AbortMultipartUpload>",
+                    _days
+                );
+            }
+            body += "</Rule>";
+        }
+        body += "</LifecycleConfiguration>";
+        let mut rqst = self.put_request("", body.into_bytes().into_boxed_slice());
+        rqst.add_params("lifecycle", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn delete_bucket_lifecycle(&self) -> Result<()> {
+        let mut rqst = self.del_request("");
+        rqst.add_params("lifecycle", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn get_bucket_cors(&self) -> Result<Vec<CorsRule>> {
+        let mut rqst = self.get_request(None);
+        rqst.add_params("cors", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        let content = std::str::from_utf8(&resp.body)?;
+        if resp.status.is_success() {
+            parse_cors(content)
+        } else if resp.status.as_u16() == 404 && content.contains("NoSuchCORSConfiguration") {
+            Ok(Vec::new())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn put_bucket_cors(&self, rules: Vec<CorsRule>) -> Result<()> {
+        let mut body = String::from(r#"<?xml version="1.0" encoding="UTF-8"?><CORSConfiguration>"#);
+        for rule in rules {
+            body += "<CORSRule>";
+            for _origin in rule.allowed_origins.iter() {
+                body += &format!("<AllowedOrigin>{}</AllowedOrigin>", escape_xml(_origin));
+            }
+            for _method in rule.allowed_methods.iter() {
+                body += &format!("<AllowedMethod>{}</AllowedMethod>", escape_xml(_method));
+            }
+            for _header in rule.allowed_headers.iter() {
+                body += &format!("<AllowedHeader>{}</AllowedHeader>", escape_xml(_header));
+            }
+            for _header in rule.expose_headers.iter() {
+                body += &format!("<ExposeHeader>{}</ExposeHeader>", escape_xml(_header));
+            }
+            if let Some(_seconds) = rule.max_age_seconds {
+                body += &format!("<MaxAgeSeconds>{}</MaxAgeSeconds>", _seconds);
+            }
+            body += "</CORSRule>";
+        }
+        body += "</CORSConfiguration>";
+        let mut rqst = self.put_request("", body.into_bytes().into_boxed_slice());
+        rqst.add_params("cors", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn delete_bucket_cors(&self) -> Result<()> {
+        let mut rqst = self.del_request("");
+        rqst.add_params("cors", None);
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn initiate_multipart<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let mut rqst = self.post_request(key.as_ref(), None);
+        rqst.add_params("uploads", None);
+        if let Some(_opts) = opts.into() {
+            _opts.check_multipart_encryption()?;
+            rqst.add_headers(_opts.as_headers());
+            rqst.add_meta(_opts.meta.unwrap_or_default());
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            read_xml_text(std::str::from_utf8(&resp.body)?, b"UploadId")?.ok_or_else(|| {
+                Error::Parse(ParseError::InvalidFormat {
+                    msg: "UploadId not found in InitiateMultipartUploadResult".to_owned(),
+                })
+            })
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn upload_part<S1, S2, D>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        data: D,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        D: Into<Box<[u8]>> + Send,
+    {
+        let mut rqst = self.put_request(key.as_ref(), data.into());
+        rqst.add_params("partNumber", part_number.to_string().as_str());
+        rqst.add_params("uploadId", upload_id.as_ref());
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(resp
+                .headers
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_owned())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn upload_part_copy<'a, S1, S2, S3, R, O>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        part_number: usize,
+        src: S3,
+        range: R,
+        opts: O,
+    ) -> Result<String>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        S3: Into<String> + Send,
+        R: Into<Option<ByteRange>> + Send,
+        O: Into<Option<PutOrCopyOptions<'a>>> + Send,
+    {
+        let opts = opts.into().unwrap_or_default();
+        let copy_source = types::copy_source(
+            opts.src_bucket.unwrap_or_else(|| self.get_bucket()),
+            &src.into(),
+            opts.src_version_id,
+        );
+        let mut rqst = self.put_request(key.as_ref(), None);
+        rqst.add_params("partNumber", part_number.to_string().as_str());
+        rqst.add_params("uploadId", upload_id.as_ref());
+        rqst.add_headers(Some(("x-oss-copy-source", format!("/{}", copy_source))));
+        if let Some(_range) = range.into() {
+            rqst.add_headers(Some(("x-oss-copy-source-range", _range.to_string())));
+        }
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let e_tag = read_xml_text(std::str::from_utf8(&resp.body)?, b"ETag")?;
+            Ok(e_tag.unwrap_or_default())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn complete_multipart<S1, S2>(
+        &self,
+        key: S1,
+        upload_id: S2,
+        parts: Vec<CompletedPart>,
+    ) -> Result<CompleteMultipartResp>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let mut body = String::from("<CompleteMultipartUpload>");
+        for part in parts {
+            body += &format!(
+                "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                part.part_number,
+                escape_xml(&part.e_tag)
+            );
+        }
+        body += "</CompleteMultipartUpload>";
+        let mut rqst = self.post_request(key.as_ref(), body.into_bytes().into_boxed_slice());
+        rqst.add_params("uploadId", upload_id.as_ref());
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            let e_tag = read_xml_text(std::str::from_utf8(&resp.body)?, b"ETag")?;
+            Ok(CompleteMultipartResp {
+                e_tag: e_tag.unwrap_or_default(),
+                version_id: version_id(&resp.headers),
+            })
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    async fn abort_multipart<S1, S2>(&self, key: S1, upload_id: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let mut rqst = self.del_request(key.as_ref());
+        rqst.add_params("uploadId", upload_id.as_ref());
+        let resp = self.sign_and_dispatch(rqst).await?;
+        if resp.status.is_success() {
+            Ok(())
+        } else {
+            Err(resp.status.as_u16().into())
+        }
+    }
+
+    // AWOS-JS 貌似省略了 content-type 和 content-md5。 这里也先省略
+    fn sign_url<'a, S, O>(&self, key: S, opts: O) -> Result<String>
+    where
+        S: AsRef<str>,
+        O: Into<Option<SignedUrlOptions<'a>>>,
+    {
+        let opts = opts.into().unwrap_or_default();
+        let expires = opts.expires.unwrap_or(3600);
+        let method = opts.method.unwrap_or("GET");
+        Ok(self.get_signed_url(key.as_ref(), method, expires, "", None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StorageClass;
+
+    #[test]
+    fn parse_lifecycle_test() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<LifecycleConfiguration>
+  <Rule>
+    <ID>rule-filter</ID>
+    <Prefix>logs/</Prefix>
+    <Tag><Key>k</Key><Value>v</Value></Tag>
+    <Status>Enabled</Status>
+    <Filter>
+      <Not>
+        <Prefix>logs/keep/</Prefix>
+        <Tag><Key>keep</Key><Value>true</Value></Tag>
+      </Not>
+    </Filter>
+    <Expiration><Days>30</Days></Expiration>
+    <Transition><Days>7</Days><StorageClass>IA</StorageClass></Transition>
+  </Rule>
+  <Rule>
+    <ID>rule-abort</ID>
+    <Prefix></Prefix>
+    <Status>Disabled</Status>
+    <AbortMultipartUpload><Days>3</Days></AbortMultipartUpload>
+  </Rule>
+</LifecycleConfiguration>"#;
+        let rules = parse_lifecycle(content).unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].id, "rule-filter");
+        assert_eq!(rules[0].prefix, "logs/");
+        assert_eq!(rules[0].tags.len(), 1);
+        assert_eq!(rules[0].tags["k"], "v");
+        assert!(rules[0].enabled);
+        assert_eq!(rules[0].expiration, Some(LifecycleTime::Days(30)));
+        assert_eq!(
+            rules[0].transitions,
+            vec![LifecycleTransition {
+                time: LifecycleTime::Days(7),
+                storage_class: StorageClass::IA,
+            }]
+        );
+        assert_eq!(rules[1].prefix, "");
+        assert!(!rules[1].enabled);
+        assert_eq!(rules[1].abort_multipart_days, Some(3));
+    }
+
+    #[test]
+    fn parse_cors_test() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CORSConfiguration>
+  <CORSRule>
+    <AllowedOrigin>https://a.example.com</AllowedOrigin>
+    <AllowedOrigin>https://b.example.com</AllowedOrigin>
+    <AllowedMethod>GET</AllowedMethod>
+    <AllowedMethod>PUT</AllowedMethod>
+    <AllowedHeader>*</AllowedHeader>
+    <ExposeHeader>ETag</ExposeHeader>
+    <MaxAgeSeconds>600</MaxAgeSeconds>
+  </CORSRule>
+  <CORSRule>
+    <AllowedOrigin>*</AllowedOrigin>
+    <AllowedMethod>HEAD</AllowedMethod>
+  </CORSRule>
+  <ResponseVary>false</ResponseVary>
+</CORSConfiguration>"#;
+        let rules = parse_cors(content).unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                CorsRule {
+                    allowed_origins: vec![
+                        "https://a.example.com".to_owned(),
+                        "https://b.example.com".to_owned()
+                    ],
+                    allowed_methods: vec!["GET".to_owned(), "PUT".to_owned()],
+                    allowed_headers: vec!["*".to_owned()],
+                    expose_headers: vec!["ETag".to_owned()],
+                    max_age_seconds: Some(600),
+                },
+                CorsRule {
+                    allowed_origins: vec!["*".to_owned()],
+                    allowed_methods: vec!["HEAD".to_owned()],
+                    ..Default::default()
+                },
+            ]
+        );
+        assert!(parse_cors("<CORSConfiguration></CORSConfiguration>")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_delete_result_test() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<DeleteResult>
+  <Deleted><Key>a</Key></Deleted>
+  <Deleted><Key>b</Key><DeleteMarker>true</DeleteMarker></Deleted>
+  <Error><Key>c</Key><Code>AccessDenied</Code><Message>Access Denied</Message></Error>
+</DeleteResult>"#;
+        let mut result = DeleteMultiResp::default();
+        parse_delete_result(content, &mut result).unwrap();
+        assert_eq!(result.deleted, vec!["a", "b"]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].key, "c");
+        assert_eq!(result.errors[0].code, "AccessDenied");
+        assert_eq!(result.errors[0].message, "Access Denied");
+    }
+
+    #[test]
+    fn parse_buckets_test() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListAllMyBucketsResult>
+  <Prefix>rust</Prefix>
+  <IsTruncated>true</IsTruncated>
+  <NextMarker>rust-b</NextMarker>
+  <Owner><ID>owner-id</ID><DisplayName>owner-name</DisplayName></Owner>
+  <Buckets>
+    <Bucket>
+      <CreationDate>2021-01-02T03:04:05.000Z</CreationDate>
+      <Location>oss-cn-beijing</Location>
+      <Name>rust-a</Name>
+      <StorageClass>Standard</StorageClass>
+    </Bucket>
+    <Bucket>
+      <Location>oss-cn-hangzhou</Location>
+      <Name>rust-b</Name>
+    </Bucket>
+  </Buckets>
+</ListAllMyBucketsResult>"#;
+        let mut result = Vec::new();
+        let next_marker = parse_buckets(content, &mut result).unwrap();
+        assert_eq!(next_marker.as_deref(), Some("rust-b"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "rust-a");
+        assert_eq!(result[0].location, "oss-cn-beijing");
+        assert_eq!(result[0].storage_class, "Standard");
+        assert!(result[0].creation_date.is_some());
+        assert_eq!(result[1].name, "rust-b");
+        assert!(result.iter().all(|_bucket| _bucket
+            .owner
+            .as_ref()
+            .is_some_and(|_owner| _owner.id == "owner-id")));
+
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<BucketInfo>
+  <Bucket>
+    <Location>oss-cn-beijing</Location>
+    <Name>rust-a</Name>
+    <Owner><DisplayName>owner-name</DisplayName><ID>owner-id</ID></Owner>
+    <AccessControlList><Grant>public-read</Grant></AccessControlList>
+  </Bucket>
+</BucketInfo>"#;
+        let mut result = Vec::new();
+        assert!(parse_buckets(content, &mut result).unwrap().is_none());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].acl, Some(Acl::PublicRead));
+        assert_eq!(result[0].owner.as_ref().unwrap().display_name, "owner-name");
+    }
+
+    fn error_response(status: u16, code: &str) -> HttpResponse {
+        HttpResponse {
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            body: Box::pin(format!("<Error><Code>{}</Code></Error>", code).into()),
+            headers: HeaderMap::new(),
+        }
+    }
+
+    #[test]
+    fn put_error_test() {
+        let error = put_error(&error_response(409, "FileAlreadyExists"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        let error = put_error(&error_response(409, "PositionNotEqualToLength"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        let error = put_error(&error_response(412, "PreconditionFailed"));
+        assert!(matches!(error, Error::PreconditionFailed));
+        // 其他 409 不视为条件不满足
+        let error = put_error(&error_response(409, "ObjectNotAppendable"));
+        assert!(matches!(error, Error::Internal { .. }));
+        let error = put_error(&error_response(404, "NoSuchBucket"));
+        assert_eq!(error.io_kind(), Some(std::io::ErrorKind::NotFound));
+        let error = precondition_error(
+            &error_response(409, "BucketAlreadyExists"),
+            &["BucketAlreadyExists"],
+        );
+        assert!(matches!(error, Error::PreconditionFailed));
+    }
+
+    #[test]
+    fn restore_error_test() {
+        for (status, code) in [
+            (400, "OperationNotSupported"),
+            (409, "RestoreAlreadyInProgress"),
+        ] {
+            let error =
+                precondition_error(&error_response(status, code), RESTORE_PRECONDITION_CODES);
+            assert!(matches!(error, Error::PreconditionFailed));
+        }
+        let error = precondition_error(
+            &error_response(400, "InvalidArgument"),
+            RESTORE_PRECONDITION_CODES,
+        );
+        assert!(matches!(error, Error::Internal { .. }));
     }
 }
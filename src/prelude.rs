@@ -1,3 +1,2 @@
 pub use super::errors::*;
-pub use super::AwosApi;
 // use crate::{Error, AwosApi};
@@ -1,13 +1,24 @@
 use bytes::Bytes;
-use rusoto_s3::{GetObjectOutput, ListObjectsOutput};
-use tokio::io::AsyncReadExt;
+use chrono::{DateTime, Utc};
+use crypto::{digest::Digest, md5::Md5};
+use reqwest::header::HeaderMap;
+use rusoto_s3::{
+    AbortIncompleteMultipartUpload, CORSRule, CommonPrefix, DeleteMarkerEntry, GetObjectOutput,
+    HeadObjectOutput, LifecycleExpiration, LifecycleRuleAndOperator, LifecycleRuleFilter,
+    ListObjectVersionsOutput, ListObjectsOutput, ListObjectsV2Output, Object, Tag, Transition,
+};
 use std::{
     collections::{HashMap, HashSet},
+    convert::{TryFrom, TryInto},
     iter::FromIterator,
     pin::Pin,
 };
+use tokio::io::AsyncReadExt;
+
+use oss_sdk::{ByteStream, HttpResponse, StreamingHttpResponse, OSS_PREFIX};
+use quick_xml::{events::Event, Reader};
 
-use oss_sdk::{HttpResponse, OSS_PREFIX};
+use crate::{Error, ParseError, Result};
 
 /// Response to Get, content encoded as String.
 #[derive(Clone, Debug, Default)]
@@ -15,13 +26,26 @@ pub struct GetResp {
     pub content: String,
     pub meta: HashMap<String, String>,
     pub headers: HashMap<String, String>,
+    pub total_size: Option<u64>,
 }
 /// Response to GetAsBuffer, content as Bytes, the same as &[u8].
+/// total_size:     Object 的总大小。指定了 range 时取自 Content-Range, 否则取自 Content-Length。
 #[derive(Clone, Debug)]
 pub struct GetAsBufferResp {
     pub content: Pin<Box<Bytes>>,
     pub meta: HashMap<String, String>,
     pub headers: HashMap<String, String>,
+    pub total_size: Option<u64>,
+}
+
+/// Response to GetStream, content as ByteStream, 不会被整个读入内存。
+/// 可以通过 body.into_async_read() 转为 AsyncRead。
+#[derive(Debug)]
+pub struct GetStreamResp {
+    pub body: ByteStream,
+    pub meta: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub total_size: Option<u64>,
 }
 
 /// Response to ListDetails
@@ -29,48 +53,341 @@ pub struct GetAsBufferResp {
 /// objects:        结果列表。
 /// prefix:         返回的 Prefix Header 项
 /// next_marker:    返回的 Next_marker Header 项，用于连续请求。
+/// common_prefixes:    指定了 delimiter 时, 被合并的公共前缀, 即 "子目录"。
+///
+/// 以下仅在 V2 模式下返回:
+/// continuation_token:         本次请求使用的 continuation_token。
+/// next_continuation_token:    用于请求下一页, 未被截断时为空。
+/// key_count:                  本次返回的 Objects 和 CommonPrefixes 的总数。
 #[derive(Clone, Debug, Default)]
 pub struct ListDetailsResp {
     pub objects: Vec<ObjectDetails>,
     pub is_truncated: bool,
     pub prefix: String,
     pub next_marker: String,
+    pub common_prefixes: Vec<String>,
+    pub continuation_token: String,
+    pub next_continuation_token: String,
+    pub key_count: usize,
 }
 /// Object 的详细信息
+/// e_tag:          去掉了两端引号的 ETag。
+/// storage_class:  存储类型, 如 Standard/IA/Archive (OSS), STANDARD/GLACIER (S3)。
+/// owner:          Object 的拥有者, V2 List 需要指定 fetch_owner 才会返回。
+/// object_type:    Object 的类型, 如 Normal/Multipart/Appendable/Symlink, S3 不返回。
 #[derive(Debug, Clone, Default)]
 pub struct ObjectDetails {
     pub key: String,
-    pub last_modified: String,
+    pub last_modified: DateTime<Utc>,
+    pub e_tag: String,
+    pub size: u64,
+    pub storage_class: String,
+    pub owner: Option<Owner>,
+    pub object_type: String,
+}
+
+/// 软链接的 object_type
+const SYMLINK_OBJECT_TYPE: &str = "Symlink";
+
+impl ObjectDetails {
+    /// 是否为软链接 (OSS Symlink)
+    pub fn is_symlink(&self) -> bool {
+        self.object_type == SYMLINK_OBJECT_TYPE
+    }
+}
+
+/// Object/Bucket 的拥有者
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Owner {
+    pub id: String,
+    pub display_name: String,
+}
+
+/// Response to ListVersions
+/// versions:               Object 的各个版本。
+/// delete_markers:         删除标记, 在开启了版本控制的 Bucket 中 del 时生成。
+/// common_prefixes:        指定了 delimiter 时, 被合并的公共前缀。
+/// next_key_marker:        被截断时, 请求下一页所用的 key_marker。
+/// next_version_id_marker: 被截断时, 请求下一页所用的 version_id_marker。
+#[derive(Clone, Debug, Default)]
+pub struct ListVersionsResp {
+    pub versions: Vec<ObjectVersion>,
+    pub delete_markers: Vec<DeleteMarker>,
+    pub is_truncated: bool,
+    pub prefix: String,
+    pub common_prefixes: Vec<String>,
+    pub next_key_marker: String,
+    pub next_version_id_marker: String,
+}
+/// Object 的一个版本
+/// version_id:     版本号, 开启版本控制前写入的 Object 为 "null"。
+/// is_latest:      是否为最新版本。
+/// e_tag:          去掉了两端引号的 ETag。
+#[derive(Clone, Debug, Default)]
+pub struct ObjectVersion {
+    pub key: String,
+    pub version_id: String,
+    pub is_latest: bool,
+    pub last_modified: DateTime<Utc>,
+    pub e_tag: String,
+    pub size: u64,
+    pub storage_class: String,
+    pub owner: Option<Owner>,
+}
+/// 删除标记, is_latest 为 true 时 Object 表现为已删除, 删除该标记即可恢复上一个版本。
+#[derive(Clone, Debug, Default)]
+pub struct DeleteMarker {
+    pub key: String,
+    pub version_id: String,
+    pub is_latest: bool,
+    pub last_modified: DateTime<Utc>,
+    pub owner: Option<Owner>,
+}
+impl From<ObjectVersion> for DeleteMarker {
+    fn from(version: ObjectVersion) -> Self {
+        Self {
+            key: version.key,
+            version_id: version.version_id,
+            is_latest: version.is_latest,
+            last_modified: version.last_modified,
+            owner: version.owner,
+        }
+    }
+}
+
+/// Response to Head, OSS 和 S3 返回的内容一致。
+/// content_length:         Object 的大小。
+/// e_tag:                  去掉了两端引号的 ETag。
+/// storage_class:          存储类型, S3 对 STANDARD 不返回该 Header, 此时补为 STANDARD。
+/// object_type:            Object 的类型, 如 Normal/Multipart/Appendable/Symlink, S3 不返回。
+///                         对 Symlink 调用 head 时, 除 object_type 外返回的是目标 Object 的信息。
+/// version_id:             开启了版本控制时, Object 的版本号。
+/// server_side_encryption: 服务端加密的算法, 如 AES256/KMS。
+/// sse_kms_key_id:         使用 KMS 加密时的 Key ID。
+/// sse_customer_algorithm: 使用客户提供的密钥 (SSE-C) 加密时的算法。
+/// restore:                归档类型 Object 的解冻状态, 没有提交过 restore 时为 None。
+/// user_meta:              用户自定义的 Metas, 不包含 x-oss-meta-/x-amz-meta- 前缀。
+#[derive(Clone, Debug, Default)]
+pub struct ObjectMeta {
+    pub content_length: u64,
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_encoding: Option<String>,
     pub e_tag: String,
-    pub size: String,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub storage_class: String,
+    pub object_type: Option<String>,
+    pub version_id: Option<String>,
+    pub server_side_encryption: Option<String>,
+    pub sse_kms_key_id: Option<String>,
+    pub sse_customer_algorithm: Option<String>,
+    pub restore: Option<RestoreStatus>,
+    pub user_meta: HashMap<String, String>,
+}
+
+/// 归档类型 Object 的解冻状态, 对应 x-oss-restore/x-amz-restore Header。
+/// ongoing:        是否正在解冻。
+/// expiry_date:    解冻完成后, 解冻副本的过期时间。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RestoreStatus {
+    pub ongoing: bool,
+    pub expiry_date: Option<DateTime<Utc>>,
+}
+
+impl std::str::FromStr for RestoreStatus {
+    type Err = Error;
+
+    /// 格式如 ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT"
+    fn from_str(s: &str) -> Result<Self> {
+        let value_of = |name: &str| {
+            let start = s.find(name)? + name.len();
+            let end = s[start..].find('"')? + start;
+            Some(&s[start..end])
+        };
+        Ok(Self {
+            ongoing: value_of("ongoing-request=\"") == Some("true"),
+            expiry_date: value_of("expiry-date=\"")
+                .map(parse_http_date)
+                .transpose()?,
+        })
+    }
+}
+
+impl ObjectMeta {
+    /// 是否为软链接 (OSS Symlink)
+    pub fn is_symlink(&self) -> bool {
+        self.object_type.as_deref() == Some(SYMLINK_OBJECT_TYPE)
+    }
+
+    /// 由 OSS 返回的 Headers 构建, headers 的 key 为小写的 Header 名称。
+    pub(crate) fn from_oss_headers(
+        user_meta: HashMap<String, String>,
+        mut headers: HashMap<String, String>,
+    ) -> Result<Self> {
+        let mut take = |k: &str| headers.remove(k);
+        Ok(Self {
+            content_length: take("content-length")
+                .map(|_len| parse_number(&_len))
+                .transpose()?
+                .unwrap_or_default(),
+            content_type: take("content-type"),
+            cache_control: take("cache-control"),
+            content_disposition: take("content-disposition"),
+            content_encoding: take("content-encoding"),
+            e_tag: take("etag")
+                .map(|_e_tag| trim_etag(&_e_tag).to_owned())
+                .unwrap_or_default(),
+            last_modified: take("last-modified")
+                .map(|_date| parse_http_date(&_date))
+                .transpose()?,
+            storage_class: take("x-oss-storage-class").unwrap_or_default(),
+            object_type: take("x-oss-object-type"),
+            version_id: take("x-oss-version-id"),
+            server_side_encryption: take("x-oss-server-side-encryption"),
+            sse_kms_key_id: take("x-oss-server-side-encryption-key-id"),
+            sse_customer_algorithm: take("x-oss-server-side-encryption-customer-algorithm"),
+            restore: take("x-oss-restore")
+                .map(|_restore| _restore.parse())
+                .transpose()?,
+            user_meta,
+        })
+    }
+}
+
+impl TryFrom<HeadObjectOutput> for ObjectMeta {
+    type Error = Error;
+
+    fn try_from(out_put: HeadObjectOutput) -> Result<Self> {
+        Ok(Self {
+            content_length: out_put.content_length.unwrap_or_default() as u64,
+            content_type: out_put.content_type,
+            cache_control: out_put.cache_control,
+            content_disposition: out_put.content_disposition,
+            content_encoding: out_put.content_encoding,
+            e_tag: out_put
+                .e_tag
+                .map(|_e_tag| trim_etag(&_e_tag).to_owned())
+                .unwrap_or_default(),
+            last_modified: out_put
+                .last_modified
+                .map(|_date| parse_http_date(&_date))
+                .transpose()?,
+            storage_class: out_put
+                .storage_class
+                .unwrap_or_else(|| "STANDARD".to_owned()),
+            object_type: None,
+            version_id: out_put.version_id,
+            server_side_encryption: out_put.server_side_encryption,
+            sse_kms_key_id: out_put.ssekms_key_id,
+            sse_customer_algorithm: out_put.sse_customer_algorithm,
+            restore: out_put
+                .restore
+                .map(|_restore| _restore.parse())
+                .transpose()?,
+            user_meta: out_put.metadata.unwrap_or_default(),
+        })
+    }
+}
+
+fn parse_number(num: &str) -> Result<u64> {
+    num.parse().map_err(|_| {
+        Error::Parse(ParseError::InvalidFormat {
+            msg: format!("Failed parsing {} to number", num),
+        })
+    })
+}
+
+/// 解析 HTTP Header 中的时间, 如 Sun, 06 Nov 1994 08:49:37 GMT。
+pub(crate) fn parse_http_date(date: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(date)
+        .map(|_date| _date.with_timezone(&Utc))
+        .map_err(|_| {
+            Error::Parse(ParseError::InvalidFormat {
+                msg: format!("Failed parsing {} to DateTime", date),
+            })
+        })
+}
+
+/// 解析 ISO8601 格式的时间, 如 2012-02-24T08:42:32.000Z。
+pub(crate) fn parse_iso8601(date: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date)
+        .map(|_date| _date.with_timezone(&Utc))
+        .map_err(|_| {
+            Error::Parse(ParseError::InvalidFormat {
+                msg: format!("Failed parsing {} to DateTime", date),
+            })
+        })
+}
+
+/// 将 OSS 返回的 Headers 分为 meta 和其他 headers 两部分
+pub(crate) fn split_headers(
+    header_map: HeaderMap,
+) -> (HashMap<String, String>, HashMap<String, String>) {
+    let mut meta = HashMap::new();
+    let mut headers = HashMap::new();
+    for (k, v) in header_map {
+        if let Some(_name) = k {
+            if _name.as_str().starts_with(OSS_PREFIX) {
+                meta.insert(
+                    _name.as_str().trim_start_matches(OSS_PREFIX).to_owned(),
+                    // _name.as_str().to_owned(),
+                    v.to_str().unwrap_or("Has invisible Ascii chars").to_owned(),
+                );
+            } else {
+                headers.insert(
+                    _name.as_str().to_owned(),
+                    v.to_str().unwrap_or("Has invisible Ascii chars").to_owned(),
+                );
+            }
+        }
+    }
+    (meta, headers)
+}
+
+/// 从 Content-Range (bytes 0-9/44) 中取出总大小, 没有时用 Content-Length。
+fn total_size(content_range: Option<&String>, content_length: Option<&String>) -> Option<u64> {
+    match content_range {
+        Some(_range) => _range
+            .rsplit('/')
+            .next()
+            .and_then(|_size| _size.parse().ok()),
+        None => content_length.and_then(|_len| _len.parse().ok()),
+    }
+}
+
+fn filter_meta(meta: &mut HashMap<String, String>, meta_keys_filter: HashSet<&str>) {
+    *meta = std::mem::take(meta)
+        .into_iter()
+        .filter(|(k, _)| {
+            meta_keys_filter.contains(k.as_str().trim_start_matches("x-oss-meta-"))
+                || meta_keys_filter.contains(k.as_str())
+        })
+        .collect();
 }
 
 // For Convenience
 impl From<HttpResponse> for GetAsBufferResp {
     fn from(resp: HttpResponse) -> Self {
-        let mut meta = HashMap::new();
-        let mut headers = HashMap::new();
-        let content = resp.body;
-        for (k, v) in resp.headers {
-            if let Some(_name) = k {
-                if _name.as_str().starts_with(OSS_PREFIX) {
-                    meta.insert(
-                        _name.as_str().trim_start_matches(OSS_PREFIX).to_owned(),
-                        // _name.as_str().to_owned(),
-                        v.to_str().unwrap_or("Has invisible Ascii chars").to_owned(),
-                    );
-                } else {
-                    headers.insert(
-                        _name.as_str().to_owned(),
-                        v.to_str().unwrap_or("Has invisible Ascii chars").to_owned(),
-                    );
-                }
-            }
+        let (meta, headers) = split_headers(resp.headers);
+        let total_size = total_size(headers.get("content-range"), headers.get("content-length"));
+        Self {
+            content: resp.body,
+            meta,
+            headers,
+            total_size,
         }
+    }
+}
+impl From<StreamingHttpResponse> for GetStreamResp {
+    fn from(resp: StreamingHttpResponse) -> Self {
+        let (meta, headers) = split_headers(resp.headers);
+        let total_size = total_size(headers.get("content-range"), headers.get("content-length"));
         Self {
-            content,
+            body: resp.body,
             meta,
             headers,
+            total_size,
         }
     }
 }
@@ -84,10 +401,10 @@ macro_rules! take_headers {
         )+
     };
 }
-impl  GetAsBufferResp {
-    pub(crate) async fn from_get_output(mut resp: GetObjectOutput) -> Self {
-        let mut buf = Vec::new();
+impl GetStreamResp {
+    pub(crate) fn from_get_output(mut resp: GetObjectOutput) -> Self {
         let meta = resp.metadata.take().unwrap_or_default();
+        let size_hint = resp.content_length.map(|_len| _len as usize);
         let mut headers = HashMap::new();
         take_headers!(
             headers,
@@ -96,6 +413,7 @@ impl  GetAsBufferResp {
             cache_control,
             bucket_key_enabled,
             content_length,
+            content_range,
             content_disposition,
             content_encoding,
             content_language,
@@ -117,28 +435,40 @@ impl  GetAsBufferResp {
             server_side_encryption,
             storage_class
         );
-
-        if let Some(_body) = resp.body.take() {
-            _body.into_async_read().read_buf(&mut buf).await.ok();
-        }
-        let content = Box::pin(buf.into());
+        let total_size = total_size(headers.get("content_range"), headers.get("content_length"));
+        let body = match (resp.body.take(), size_hint) {
+            (Some(_body), Some(_size)) => ByteStream::new_with_size(_body, _size),
+            (Some(_body), None) => ByteStream::new(_body),
+            (None, _) => Vec::new().into(),
+        };
         Self {
-            content,
+            body,
             meta,
             headers,
+            total_size,
         }
     }
+
+    pub(crate) fn filter(&mut self, meta_keys_filter: HashSet<&str>) {
+        filter_meta(&mut self.meta, meta_keys_filter);
+    }
+
+    /// 将 body 全部读入内存
+    pub(crate) async fn into_buffer(self) -> Result<GetAsBufferResp> {
+        let mut buf = Vec::with_capacity(self.body.size_hint().unwrap_or_default());
+        self.body.into_async_read().read_to_end(&mut buf).await?;
+        Ok(GetAsBufferResp {
+            content: Box::pin(buf.into()),
+            meta: self.meta,
+            headers: self.headers,
+            total_size: self.total_size,
+        })
+    }
 }
 
 impl GetAsBufferResp {
     pub(crate) fn filter(&mut self, meta_keys_filter: HashSet<&str>) {
-        self.meta = std::mem::take(&mut self.meta)
-            .into_iter()
-            .filter(|(k, _)| {
-                meta_keys_filter.contains(k.as_str().trim_start_matches("x-oss-meta-"))
-                    || meta_keys_filter.contains(k.as_str())
-            })
-            .collect();
+        filter_meta(&mut self.meta, meta_keys_filter);
     }
 }
 impl From<GetAsBufferResp> for GetResp {
@@ -151,35 +481,153 @@ impl From<GetAsBufferResp> for GetResp {
                 .unwrap_or("Failed when encoding to string.".to_owned()),
             meta,
             headers,
+            total_size: resp.total_size,
         }
     }
 }
-impl From<ListObjectsOutput> for ListDetailsResp {
-    fn from(mut out_put: ListObjectsOutput) -> Self {
-        let objects = out_put.contents.take().map(|_obj_vec| {
-            _obj_vec
+impl TryFrom<Object> for ObjectDetails {
+    type Error = Error;
+
+    fn try_from(mut obj: Object) -> Result<Self> {
+        Ok(ObjectDetails {
+            key: obj.key.take().unwrap_or_default(),
+            last_modified: match obj.last_modified.take() {
+                Some(_last_modified) => parse_iso8601(&_last_modified)?,
+                None => Default::default(),
+            },
+            e_tag: obj
+                .e_tag
+                .take()
+                .map(|_e_tag| trim_etag(&_e_tag).to_owned())
+                .unwrap_or_default(),
+            size: obj.size.take().unwrap_or_default() as u64,
+            storage_class: obj.storage_class.take().unwrap_or_default(),
+            owner: from_s3_owner(obj.owner.take()),
+            object_type: String::new(),
+        })
+    }
+}
+fn from_s3_contents(contents: Option<Vec<Object>>) -> Result<Vec<ObjectDetails>> {
+    contents
+        .unwrap_or_default()
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect()
+}
+fn from_s3_common_prefixes(common_prefixes: Option<Vec<CommonPrefix>>) -> Vec<String> {
+    common_prefixes
+        .map(|_prefix_vec| {
+            _prefix_vec
                 .into_iter()
-                .map(|mut _obj| ObjectDetails {
-                    key: _obj.key.take().unwrap_or_default(),
-                    last_modified: _obj.last_modified.take().unwrap_or_default(),
-                    e_tag: _obj.e_tag.take().unwrap_or_default(),
-                    size: _obj
-                        .size
-                        .take()
-                        .map(|_size| _size.to_string())
-                        .unwrap_or_default(),
-                })
-                .collect::<Vec<_>>()
-        });
-        ListDetailsResp {
+                .filter_map(|_common_prefix| _common_prefix.prefix)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+impl TryFrom<ListObjectsOutput> for ListDetailsResp {
+    type Error = Error;
+
+    fn try_from(mut out_put: ListObjectsOutput) -> Result<Self> {
+        Ok(ListDetailsResp {
             is_truncated: out_put.is_truncated.take().unwrap_or_default(),
             next_marker: out_put.next_marker.take().unwrap_or_default(),
             prefix: out_put.prefix.take().unwrap_or_default(),
-            objects: objects.unwrap_or_default(),
-        }
+            objects: from_s3_contents(out_put.contents.take())?,
+            common_prefixes: from_s3_common_prefixes(out_put.common_prefixes.take()),
+            ..Default::default()
+        })
+    }
+}
+impl TryFrom<ListObjectsV2Output> for ListDetailsResp {
+    type Error = Error;
+
+    fn try_from(mut out_put: ListObjectsV2Output) -> Result<Self> {
+        Ok(ListDetailsResp {
+            is_truncated: out_put.is_truncated.take().unwrap_or_default(),
+            prefix: out_put.prefix.take().unwrap_or_default(),
+            objects: from_s3_contents(out_put.contents.take())?,
+            common_prefixes: from_s3_common_prefixes(out_put.common_prefixes.take()),
+            continuation_token: out_put.continuation_token.take().unwrap_or_default(),
+            next_continuation_token: out_put.next_continuation_token.take().unwrap_or_default(),
+            key_count: out_put.key_count.take().unwrap_or_default() as usize,
+            ..Default::default()
+        })
+    }
+}
+pub(crate) fn from_s3_owner(owner: Option<rusoto_s3::Owner>) -> Option<Owner> {
+    owner.map(|_owner| Owner {
+        id: _owner.id.unwrap_or_default(),
+        display_name: _owner.display_name.unwrap_or_default(),
+    })
+}
+impl TryFrom<rusoto_s3::ObjectVersion> for ObjectVersion {
+    type Error = Error;
+
+    fn try_from(mut version: rusoto_s3::ObjectVersion) -> Result<Self> {
+        Ok(ObjectVersion {
+            key: version.key.take().unwrap_or_default(),
+            version_id: version.version_id.take().unwrap_or_default(),
+            is_latest: version.is_latest.take().unwrap_or_default(),
+            last_modified: match version.last_modified.take() {
+                Some(_last_modified) => parse_iso8601(&_last_modified)?,
+                None => Default::default(),
+            },
+            e_tag: version
+                .e_tag
+                .take()
+                .map(|_e_tag| trim_etag(&_e_tag).to_owned())
+                .unwrap_or_default(),
+            size: version.size.take().unwrap_or_default() as u64,
+            storage_class: version.storage_class.take().unwrap_or_default(),
+            owner: from_s3_owner(version.owner.take()),
+        })
+    }
+}
+impl TryFrom<DeleteMarkerEntry> for DeleteMarker {
+    type Error = Error;
+
+    fn try_from(mut marker: DeleteMarkerEntry) -> Result<Self> {
+        Ok(DeleteMarker {
+            key: marker.key.take().unwrap_or_default(),
+            version_id: marker.version_id.take().unwrap_or_default(),
+            is_latest: marker.is_latest.take().unwrap_or_default(),
+            last_modified: match marker.last_modified.take() {
+                Some(_last_modified) => parse_iso8601(&_last_modified)?,
+                None => Default::default(),
+            },
+            owner: from_s3_owner(marker.owner.take()),
+        })
+    }
+}
+impl TryFrom<ListObjectVersionsOutput> for ListVersionsResp {
+    type Error = Error;
+
+    fn try_from(mut out_put: ListObjectVersionsOutput) -> Result<Self> {
+        Ok(ListVersionsResp {
+            versions: out_put
+                .versions
+                .take()
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            delete_markers: out_put
+                .delete_markers
+                .take()
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            is_truncated: out_put.is_truncated.take().unwrap_or_default(),
+            prefix: out_put.prefix.take().unwrap_or_default(),
+            common_prefixes: from_s3_common_prefixes(out_put.common_prefixes.take()),
+            next_key_marker: out_put.next_key_marker.take().unwrap_or_default(),
+            next_version_id_marker: out_put.next_version_id_marker.take().unwrap_or_default(),
+        })
     }
 }
 impl ListDetailsResp {
+    #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_obj_names<R>(self) -> R
     where
         R: FromIterator<String>,
@@ -188,8 +636,147 @@ impl ListDetailsResp {
     }
 }
 
+/// Get 时读取的字节范围, 对应 HTTP Range Header。
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum ByteRange {
+    /// 从 start 到 end 的字节, 包含 end。
+    #[display(fmt = "bytes={}-{}", _0, _1)]
+    Range(u64, u64),
+    /// 从 start 开始直到结尾。
+    #[display(fmt = "bytes={}-", _0)]
+    From(u64),
+    /// 最后的 n 个字节。
+    #[display(fmt = "bytes=-{}", _0)]
+    Suffix(u64),
+}
+
+/// Get 相关操作的可选参数
+/// range:                  只读取 Object 的一部分, 见 ByteRange。
+/// if_match:               ETag 与之相同时才返回 Object, 否则返回 Error::PreconditionFailed。
+/// if_none_match:          ETag 与之不同时才返回 Object, 否则返回 Error::NotModified。
+/// if_modified_since:      在此时间之后修改过才返回 Object, 否则返回 Error::NotModified。
+/// if_unmodified_since:    在此时间之后没有修改过才返回 Object, 否则返回 Error::PreconditionFailed。
+/// sse_customer_key:       Object 使用客户提供的密钥 (SSE-C) 加密时, 读取需要提供相同的密钥。
+/// version_id:             读取指定版本的 Object, 默认为最新版本。
+#[derive(Clone, Debug, Default)]
+pub struct GetOptions {
+    pub range: Option<ByteRange>,
+    pub if_match: Option<String>,
+    pub if_none_match: Option<String>,
+    pub if_modified_since: Option<DateTime<Utc>>,
+    pub if_unmodified_since: Option<DateTime<Utc>>,
+    pub sse_customer_key: Option<Vec<u8>>,
+    pub version_id: Option<String>,
+}
+
+impl GetOptions {
+    /// GetOptions 构建, 参数皆为可选。
+    ///
+    /// #Example
+    /// ```
+    /// let get_opts = awos_rust::GetOptions::new(awos_rust::ByteRange::Suffix(1024), None, None, None, None);
+    /// ```
+    pub fn new<R, S1, S2, T1, T2>(
+        range: R,
+        if_match: S1,
+        if_none_match: S2,
+        if_modified_since: T1,
+        if_unmodified_since: T2,
+    ) -> Self
+    where
+        R: Into<Option<ByteRange>>,
+        S1: Into<Option<String>>,
+        S2: Into<Option<String>>,
+        T1: Into<Option<DateTime<Utc>>>,
+        T2: Into<Option<DateTime<Utc>>>,
+    {
+        Self {
+            range: range.into(),
+            if_match: if_match.into(),
+            if_none_match: if_none_match.into(),
+            if_modified_since: if_modified_since.into(),
+            if_unmodified_since: if_unmodified_since.into(),
+            sse_customer_key: None,
+            version_id: None,
+        }
+    }
+
+    pub(crate) fn as_headers(&self) -> HashMap<&str, String> {
+        let mut headers = HashMap::with_capacity(6);
+        if let Some(_range) = self.range {
+            headers.insert("range", _range.to_string());
+            // 范围不合法时返回 416, 而不是整个 Object, 与 S3 一致。
+            headers.insert("x-oss-range-behavior", "standard".to_owned());
+        }
+        let mut add_headers = |k, v| {
+            if let Some(_v) = v {
+                headers.insert(k, _v);
+            }
+        };
+        add_headers("if-match", self.if_match.clone());
+        add_headers("if-none-match", self.if_none_match.clone());
+        add_headers("if-modified-since", self.if_modified_since.map(http_date));
+        add_headers(
+            "if-unmodified-since",
+            self.if_unmodified_since.map(http_date),
+        );
+        if let Some(_key) = self.sse_customer_key.as_ref() {
+            headers.extend(
+                OSS_SSE_C_HEADERS
+                    .iter()
+                    .copied()
+                    .zip(sse_customer_values(_key)),
+            );
+        }
+        headers
+    }
+}
+
+/// 读取 XML 中第一个 tag 的文本内容
+pub(crate) fn read_xml_text(content: &str, tag: &[u8]) -> Result<Option<String>> {
+    let mut reader = Reader::from_str(content);
+    let mut buf = Vec::new();
+    reader.trim_text(true);
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) if e.name() == tag => {
+                return Ok(Some(reader.read_text(e.name(), &mut Vec::new())?))
+            }
+            Event::Eof => return Ok(None),
+            _ => (),
+        }
+        buf.clear();
+    }
+}
+
+/// HTTP Header 中使用的时间格式, 如 Sun, 06 Nov 1994 08:49:37 GMT。
+pub(crate) fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
 /// 上传/复制 Object 方法的可选参数
 /// 不为空时，会在请求中添加对映的 Header
+///
+/// 以下条件针对目标 Object, 不满足时 put/copy 返回 Error::PreconditionFailed:
+/// if_match:           目标 Object 存在且 ETag 与之相同时才写入, 对应 OSS 的 If-Match。
+/// if_none_match:      仅支持 "*", 表示仅在不存在时创建。
+/// forbid_overwrite:   为 true 时同 if_none_match 为 "*", 对应 OSS 的 x-oss-forbid-overwrite。
+///
+/// 条件由服务端在写入时判断, 不存在先 HEAD 再写入的并发窗口。
+/// if_none_match 为具体的 ETag, 或 copy_large 设置了 if_match 时返回 Error::Unsupported。
+/// S3 只有 PutObject 支持条件写入, 因此只用于 put 和已知大小的 put_stream,
+/// copy/copy_large/put_multipart 设置了任一条件时返回 Error::Unsupported。
+///
+/// encryption:         服务端加密方式, 见 ServerSideEncryption。
+/// storage_class:      Object 的存储类型, 见 StorageClass。
+/// acl:                Object 的访问权限, 见 Acl。
+/// tags:               Object 的标签, copy 时设置了 tags 则替换源 Object 的标签, 否则沿用。
+///
+/// 以下参数仅用于 copy:
+/// src_bucket:         源 Object 所在的 Bucket, 默认与目标相同。
+/// src_version_id:     源 Object 的版本。
+/// src_sse_customer_key:   源 Object 使用 SSE-C 加密时, 需要提供其密钥。
+/// metadata_directive: 见 MetadataDirective, 默认在设置了 meta 或 content_* 等 Header 时为 Replace, 否则为 Copy。
 #[derive(Debug, Default)]
 pub struct PutOrCopyOptions<'a> {
     pub meta: Option<HashMap<String, String>>,
@@ -197,6 +784,17 @@ pub struct PutOrCopyOptions<'a> {
     pub cache_control: Option<&'a str>,
     pub content_disposition: Option<&'a str>,
     pub content_encoding: Option<&'a str>,
+    pub if_match: Option<&'a str>,
+    pub if_none_match: Option<&'a str>,
+    pub forbid_overwrite: Option<bool>,
+    pub encryption: Option<ServerSideEncryption>,
+    pub storage_class: Option<StorageClass>,
+    pub acl: Option<Acl>,
+    pub tags: Option<HashMap<String, String>>,
+    pub src_bucket: Option<&'a str>,
+    pub src_version_id: Option<&'a str>,
+    pub src_sse_customer_key: Option<&'a [u8]>,
+    pub metadata_directive: Option<MetadataDirective>,
 }
 
 impl<'a> PutOrCopyOptions<'a> {
@@ -205,7 +803,13 @@ impl<'a> PutOrCopyOptions<'a> {
     ///
     /// #Example
     /// ```
-    /// let put_opts = awos_rust::PutOrCopyOptions::new(vec![("test-key", "test-val")], "content-type-unknown", None, None, None);
+    /// let meta = vec![("test-key".to_owned(), "test-val".to_owned())].into_iter().collect::<std::collections::HashMap<_, _>>();
+    /// let put_opts = awos_rust::PutOrCopyOptions::new(meta, "content-type-unknown", None, None, None);
+    /// // 仅在不存在时创建
+    /// let create_opts = awos_rust::PutOrCopyOptions {
+    ///     forbid_overwrite: Some(true),
+    ///     ..Default::default()
+    /// };
     /// ```
     pub fn new<M, S1, S2, S3, S4>(
         meta: M,
@@ -227,30 +831,710 @@ impl<'a> PutOrCopyOptions<'a> {
             content_type: content_type.into(),
             content_disposition: content_disposition.into(),
             content_encoding: content_encoding.into(),
+            ..Default::default()
         }
     }
 
-    pub(crate) fn as_headers(&self) -> HashMap<&str, &str> {
+    pub(crate) fn as_headers(&self) -> HashMap<&str, String> {
         let mut headers = HashMap::with_capacity(4);
-        let mut add_headers = |k, v| {
+        let mut add_headers = |k, v: Option<&str>| {
             if let Some(_v) = v {
-                headers.insert(k, _v);
+                headers.insert(k, _v.to_owned());
             }
         };
-        add_headers("cache_control", self.content_type);
-        add_headers("content_type", self.content_type);
-        add_headers("content_disposition", self.content_type);
-        add_headers("content_encoding", self.content_type);
+        add_headers("cache-control", self.cache_control);
+        add_headers("content-type", self.content_type);
+        add_headers("content-disposition", self.content_disposition);
+        add_headers("content-encoding", self.content_encoding);
+        if let Some(_etag) = self.if_match {
+            headers.insert("if-match", _etag.to_owned());
+        }
+        if self.forbids_overwrite() {
+            headers.insert("x-oss-forbid-overwrite", "true".to_owned());
+        }
+        if let Some(_encryption) = self.encryption.as_ref() {
+            headers.extend(_encryption.as_oss_headers());
+        }
+        if let Some(_key) = self.src_sse_customer_key {
+            headers.extend(
+                OSS_COPY_SOURCE_SSE_C_HEADERS
+                    .iter()
+                    .copied()
+                    .zip(sse_customer_values(_key)),
+            );
+        }
+        if let Some(_storage_class) = self.storage_class {
+            headers.insert(
+                "x-oss-storage-class",
+                _storage_class.as_oss_str().to_owned(),
+            );
+        }
+        if let Some(_acl) = self.acl {
+            headers.insert("x-oss-object-acl", _acl.to_string());
+        }
+        if let Some(_tagging) = self.tagging() {
+            headers.insert("x-oss-tagging", _tagging);
+        }
         headers
     }
+
+    /// tags 编码为 x-oss-tagging/x-amz-tagging 的格式, 如 k1=v1&k2=v2。
+    pub(crate) fn tagging(&self) -> Option<String> {
+        self.tags.as_ref().map(|_tags| {
+            _tags
+                .iter()
+                .map(|(k, v)| format!("{}={}", encode_uri(k, true), encode_uri(v, true)))
+                .collect::<Vec<_>>()
+                .join("&")
+        })
+    }
+
+    /// copy 时实际使用的 MetadataDirective
+    pub(crate) fn metadata_directive(&self) -> MetadataDirective {
+        self.metadata_directive.unwrap_or_else(|| {
+            let replaces = self.meta.is_some()
+                || self.content_type.is_some()
+                || self.cache_control.is_some()
+                || self.content_disposition.is_some()
+                || self.content_encoding.is_some();
+            if replaces {
+                MetadataDirective::Replace
+            } else {
+                MetadataDirective::Copy
+            }
+        })
+    }
+
+    /// 目标已存在时是否禁止写入
+    pub(crate) fn forbids_overwrite(&self) -> bool {
+        self.forbid_overwrite.unwrap_or_default() || self.if_none_match == Some("*")
+    }
+
+    /// 是否设置了任一写入条件
+    pub(crate) fn has_put_conditions(&self) -> bool {
+        self.if_match.is_some() || self.if_none_match.is_some() || self.forbids_overwrite()
+    }
+
+    /// upload_part/upload_part_copy 不会带上 SSE-C 的密钥, 在开始 Multipart Upload 之前拒绝。
+    pub(crate) fn check_multipart_encryption(&self) -> Result<()> {
+        let is_customer = matches!(self.encryption, Some(ServerSideEncryption::Customer { .. }));
+        if is_customer || self.src_sse_customer_key.is_some() {
+            Err(Error::Unsupported {
+                msg: "SSE-C is not supported with multipart upload".to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 检查服务端能否判断这些写入条件, multipart 写入时 If-Match 无法在合并时生效。
+    pub(crate) fn check_put_conditions(&self, multipart: bool) -> Result<()> {
+        if self.if_none_match.is_some_and(|_etag| _etag != "*") {
+            Err(Error::Unsupported {
+                msg: "only if_none_match \"*\" is supported".to_owned(),
+            })
+        } else if multipart && self.if_match.is_some() {
+            Err(Error::Unsupported {
+                msg: "if_match is not supported with multipart upload".to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// copy 时目标 Object 元数据的来源。
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum MetadataDirective {
+    /// 沿用源 Object 的元数据, 忽略 PutOrCopyOptions 中的 meta 和 Header。
+    #[display(fmt = "COPY")]
+    Copy,
+    /// 使用 PutOrCopyOptions 中的 meta 和 Header 替换。
+    #[display(fmt = "REPLACE")]
+    Replace,
+}
+
+/// 服务端加密方式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServerSideEncryption {
+    /// 由服务端托管密钥, 即 OSS 的 SSE-OSS 和 S3 的 SSE-S3, 算法为 AES256。
+    Managed,
+    /// 使用 KMS 托管的密钥, key_id 为 None 时使用默认的 KMS Key。
+    Kms { key_id: Option<String> },
+    /// 使用客户提供的 256 位密钥 (SSE-C), 读取和复制时需要提供相同的密钥。
+    /// 不支持 Multipart Upload, initiate_multipart/put_multipart/copy_large 返回 Error::Unsupported。
+    Customer { key: Vec<u8> },
+}
+
+impl ServerSideEncryption {
+    fn as_oss_headers(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ServerSideEncryption::Managed => {
+                vec![("x-oss-server-side-encryption", "AES256".to_owned())]
+            }
+            ServerSideEncryption::Kms { key_id } => {
+                let mut headers = vec![("x-oss-server-side-encryption", "KMS".to_owned())];
+                if let Some(_key_id) = key_id {
+                    headers.push(("x-oss-server-side-encryption-key-id", _key_id.to_owned()));
+                }
+                headers
+            }
+            ServerSideEncryption::Customer { key } => OSS_SSE_C_HEADERS
+                .iter()
+                .copied()
+                .zip(sse_customer_values(key))
+                .collect(),
+        }
+    }
+}
+
+const OSS_SSE_C_HEADERS: [&str; 3] = [
+    "x-oss-server-side-encryption-customer-algorithm",
+    "x-oss-server-side-encryption-customer-key",
+    "x-oss-server-side-encryption-customer-key-md5",
+];
+
+const OSS_COPY_SOURCE_SSE_C_HEADERS: [&str; 3] = [
+    "x-oss-copy-source-server-side-encryption-customer-algorithm",
+    "x-oss-copy-source-server-side-encryption-customer-key",
+    "x-oss-copy-source-server-side-encryption-customer-key-md5",
+];
+
+/// SSE-C 的算法, Base64 编码的密钥和密钥的 MD5。
+pub(crate) fn sse_customer_values(key: &[u8]) -> [String; 3] {
+    let mut hasher = Md5::new();
+    hasher.input(key);
+    let mut digest = [0u8; 16];
+    hasher.result(&mut digest);
+    [
+        "AES256".to_owned(),
+        base64::encode(key),
+        base64::encode(digest),
+    ]
+}
+
+/// Object 的存储类型, OSS 和 S3 中对应的名称不同。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageClass {
+    /// 标准存储, OSS Standard, S3 STANDARD。
+    Standard,
+    /// 低频访问, OSS IA, S3 STANDARD_IA。
+    IA,
+    /// 归档存储, 读取前需要 restore, OSS Archive, S3 GLACIER。
+    Archive,
+    /// 冷归档存储, 读取前需要 restore, OSS ColdArchive, S3 DEEP_ARCHIVE。
+    ColdArchive,
+}
+
+impl StorageClass {
+    pub(crate) fn as_oss_str(self) -> &'static str {
+        match self {
+            StorageClass::Standard => "Standard",
+            StorageClass::IA => "IA",
+            StorageClass::Archive => "Archive",
+            StorageClass::ColdArchive => "ColdArchive",
+        }
+    }
+
+    pub(crate) fn as_s3_str(self) -> &'static str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::IA => "STANDARD_IA",
+            StorageClass::Archive => "GLACIER",
+            StorageClass::ColdArchive => "DEEP_ARCHIVE",
+        }
+    }
+}
+
+impl std::str::FromStr for StorageClass {
+    type Err = Error;
+
+    /// 同时接受 OSS 和 S3 的名称
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "Standard" | "STANDARD" => Ok(StorageClass::Standard),
+            "IA" | "STANDARD_IA" => Ok(StorageClass::IA),
+            "Archive" | "GLACIER" => Ok(StorageClass::Archive),
+            "ColdArchive" | "DEEP_ARCHIVE" => Ok(StorageClass::ColdArchive),
+            _ => Err(Error::Parse(ParseError::InvalidFormat {
+                msg: format!("Unknown storage class {}", s),
+            })),
+        }
+    }
+}
+
+/// 解冻的优先级, 越快费用越高。
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum RestoreTier {
+    Expedited,
+    Standard,
+    Bulk,
+}
+
+/// restore 的可选参数
+/// days:   解冻副本的保留天数, 默认 1 天。
+/// tier:   解冻的优先级, 默认由服务端决定。OSS 仅 ColdArchive 支持。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RestoreOptions {
+    pub days: Option<u32>,
+    pub tier: Option<RestoreTier>,
+}
+
+/// 预设的访问权限 (Canned ACL), 用于 Object 和 Bucket。
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Acl {
+    /// 私有, 只有拥有者可以读写。
+    #[display(fmt = "private")]
+    Private,
+    /// 公共读, 所有人可以读, 只有拥有者可以写。
+    #[display(fmt = "public-read")]
+    PublicRead,
+    /// 公共读写, 所有人可以读写。
+    #[display(fmt = "public-read-write")]
+    PublicReadWrite,
+    /// 继承 Bucket 的权限, 仅适用于 OSS 的 Object。S3 没有该权限, put/copy 时不设置 ACL,
+    /// 由 S3 的默认权限决定, put_object_acl 返回 Error::Unsupported。
+    #[display(fmt = "default")]
+    Default,
+}
+
+impl std::str::FromStr for Acl {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "private" => Ok(Acl::Private),
+            "public-read" => Ok(Acl::PublicRead),
+            "public-read-write" => Ok(Acl::PublicReadWrite),
+            "default" => Ok(Acl::Default),
+            _ => Err(Error::Parse(ParseError::InvalidFormat {
+                msg: format!("Unknown ACL {}", s),
+            })),
+        }
+    }
+}
+
+/// Bucket 没有 Acl::Default, 用于 create_bucket 和 put_bucket_acl。
+pub(crate) fn check_bucket_acl(acl: Acl) -> Result<()> {
+    if acl == Acl::Default {
+        Err(Error::Unsupported {
+            msg: "Bucket does not support Acl::Default".to_owned(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// 创建 Bucket 的可选参数
+/// region:         Bucket 所在的地域, OSS 如 oss-cn-beijing, S3 作为 LocationConstraint, 默认为 Client 所在的地域。
+/// acl:            Bucket 的访问权限, 默认为 private, 不支持 Acl::Default, 此时返回 Error::Unsupported。
+/// storage_class:  Bucket 的默认存储类型, 仅 OSS 支持, S3 没有 Bucket 级别的存储类型, 会忽略该参数。
+#[derive(Clone, Debug, Default)]
+pub struct CreateBucketOptions<'a> {
+    pub region: Option<&'a str>,
+    pub acl: Option<Acl>,
+    pub storage_class: Option<StorageClass>,
+}
+
+/// Bucket 的信息, 用于 get_bucket_info 和 list_buckets
+/// location:       Bucket 所在的地域, 如 oss-cn-beijing/us-east-1。S3 的 list_buckets 不返回。
+/// creation_date:  Bucket 的创建时间。
+/// storage_class:  Bucket 的默认存储类型, 仅 OSS 返回。
+/// acl:            Bucket 的访问权限, list_buckets 不返回。
+#[derive(Clone, Debug, Default)]
+pub struct BucketInfo {
+    pub name: String,
+    pub location: String,
+    pub creation_date: Option<DateTime<Utc>>,
+    pub storage_class: String,
+    pub acl: Option<Acl>,
+    pub owner: Option<Owner>,
+}
+
+/// Bucket 的生命周期规则, put_lifecycle 时会整体替换 Bucket 已有的规则。
+/// id:                     规则的 ID, 在 Bucket 内唯一, 为空时由服务端生成。
+/// enabled:                规则是否生效。
+/// prefix:                 规则作用的 Object 前缀, 为空时作用于整个 Bucket。
+/// tags:                   规则只作用于同时带有这些标签的 Object。
+/// expiration:             Object 被删除的时间。
+/// transitions:            Object 转换存储类型的时间, 见 LifecycleTransition。
+/// abort_multipart_days:   未完成的 Multipart Upload 在初始化多少天后被删除。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LifecycleRule {
+    pub id: String,
+    pub enabled: bool,
+    pub prefix: String,
+    pub tags: HashMap<String, String>,
+    pub expiration: Option<LifecycleTime>,
+    pub transitions: Vec<LifecycleTransition>,
+    pub abort_multipart_days: Option<u32>,
+}
+
+/// 生命周期规则中的时间条件
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleTime {
+    /// 距 Object 最后修改时间的天数。
+    Days(u32),
+    /// 最后修改时间在此日期之前的 Object, 只取 UTC 的日期部分。
+    Date(DateTime<Utc>),
+}
+
+/// 生命周期规则中的存储类型转换, 在 time 之后将 Object 转为 storage_class。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LifecycleTransition {
+    pub time: LifecycleTime,
+    pub storage_class: StorageClass,
+}
+
+/// 生命周期规则中的日期格式, OSS 和 S3 都要求为 UTC 零点, 如 2021-01-01T00:00:00.000Z。
+pub(crate) fn lifecycle_date(date: DateTime<Utc>) -> String {
+    date.format("%Y-%m-%dT00:00:00.000Z").to_string()
+}
+
+fn to_s3_days_and_date(time: LifecycleTime) -> (Option<i64>, Option<String>) {
+    match time {
+        LifecycleTime::Days(_days) => (Some(_days as i64), None),
+        LifecycleTime::Date(_date) => (None, Some(lifecycle_date(_date))),
+    }
+}
+fn from_s3_days_and_date(days: Option<i64>, date: Option<String>) -> Result<Option<LifecycleTime>> {
+    match (days, date) {
+        (Some(_days), _) => Ok(Some(LifecycleTime::Days(_days as u32))),
+        (None, Some(_date)) => Ok(Some(LifecycleTime::Date(parse_iso8601(&_date)?))),
+        (None, None) => Ok(None),
+    }
+}
+impl From<LifecycleRule> for rusoto_s3::LifecycleRule {
+    fn from(rule: LifecycleRule) -> Self {
+        let mut tags: Vec<_> = rule
+            .tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        // 只有一个条件时不能使用 And
+        let filter = match (rule.prefix.is_empty(), tags.len()) {
+            (_, 0) => LifecycleRuleFilter {
+                prefix: Some(rule.prefix),
+                ..Default::default()
+            },
+            (true, 1) => LifecycleRuleFilter {
+                tag: tags.pop(),
+                ..Default::default()
+            },
+            (prefix_is_empty, _) => LifecycleRuleFilter {
+                and: Some(LifecycleRuleAndOperator {
+                    prefix: Some(rule.prefix).filter(|_| !prefix_is_empty),
+                    tags: Some(tags),
+                }),
+                ..Default::default()
+            },
+        };
+        let transitions = rule
+            .transitions
+            .into_iter()
+            .map(|_transition| {
+                let (days, date) = to_s3_days_and_date(_transition.time);
+                Transition {
+                    days,
+                    date,
+                    storage_class: Some(_transition.storage_class.as_s3_str().to_owned()),
+                }
+            })
+            .collect::<Vec<_>>();
+        Self {
+            id: Some(rule.id).filter(|_id| !_id.is_empty()),
+            status: if rule.enabled { "Enabled" } else { "Disabled" }.to_owned(),
+            filter: Some(filter),
+            expiration: rule.expiration.map(|_expiration| {
+                let (days, date) = to_s3_days_and_date(_expiration);
+                LifecycleExpiration {
+                    days,
+                    date,
+                    ..Default::default()
+                }
+            }),
+            transitions: Some(transitions).filter(|_transitions| !_transitions.is_empty()),
+            abort_incomplete_multipart_upload: rule.abort_multipart_days.map(|_days| {
+                AbortIncompleteMultipartUpload {
+                    days_after_initiation: Some(_days as i64),
+                }
+            }),
+            ..Default::default()
+        }
+    }
+}
+impl TryFrom<rusoto_s3::LifecycleRule> for LifecycleRule {
+    type Error = Error;
+
+    fn try_from(rule: rusoto_s3::LifecycleRule) -> Result<Self> {
+        let filter = rule.filter.unwrap_or_default();
+        let (prefix, tags) = match filter.and {
+            Some(_and) => (_and.prefix, _and.tags.unwrap_or_default()),
+            None => (filter.prefix, filter.tag.into_iter().collect()),
+        };
+        Ok(Self {
+            id: rule.id.unwrap_or_default(),
+            enabled: rule.status == "Enabled",
+            prefix: prefix.unwrap_or_default(),
+            tags: tags
+                .into_iter()
+                .map(|_tag| (_tag.key, _tag.value))
+                .collect(),
+            expiration: match rule.expiration {
+                Some(_expiration) => from_s3_days_and_date(_expiration.days, _expiration.date)?,
+                None => None,
+            },
+            transitions: rule
+                .transitions
+                .unwrap_or_default()
+                .into_iter()
+                .map(|_transition| {
+                    Ok(LifecycleTransition {
+                        time: from_s3_days_and_date(_transition.days, _transition.date)?
+                            .ok_or_else(|| {
+                                Error::Parse(ParseError::InvalidFormat {
+                                    msg: "Days or Date not found in Transition".to_owned(),
+                                })
+                            })?,
+                        storage_class: _transition.storage_class.unwrap_or_default().parse()?,
+                    })
+                })
+                .collect::<Result<_>>()?,
+            abort_multipart_days: rule
+                .abort_incomplete_multipart_upload
+                .and_then(|_abort| _abort.days_after_initiation)
+                .map(|_days| _days as u32),
+        })
+    }
+}
+
+/// Bucket 的跨域规则 (CORS), put_bucket_cors 时会整体替换 Bucket 已有的规则。
+/// allowed_origins:        允许的跨域请求来源, 如 https://example.com, 可包含一个 "*" 通配符。
+/// allowed_methods:        允许的跨域请求方法, 如 GET/PUT/POST/DELETE/HEAD。
+/// allowed_headers:        预检请求 Access-Control-Request-Headers 中允许的 Header, 可使用 "*"。
+/// expose_headers:         允许浏览器中的应用读取的响应 Header, 如 ETag。
+/// max_age_seconds:        浏览器缓存预检请求结果的时间。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub expose_headers: Vec<String>,
+    pub max_age_seconds: Option<u32>,
+}
+
+impl From<CorsRule> for CORSRule {
+    fn from(rule: CorsRule) -> Self {
+        Self {
+            allowed_origins: rule.allowed_origins,
+            allowed_methods: rule.allowed_methods,
+            allowed_headers: Some(rule.allowed_headers).filter(|_headers| !_headers.is_empty()),
+            expose_headers: Some(rule.expose_headers).filter(|_headers| !_headers.is_empty()),
+            max_age_seconds: rule.max_age_seconds.map(|_seconds| _seconds as i64),
+        }
+    }
+}
+impl From<CORSRule> for CorsRule {
+    fn from(rule: CORSRule) -> Self {
+        Self {
+            allowed_origins: rule.allowed_origins,
+            allowed_methods: rule.allowed_methods,
+            allowed_headers: rule.allowed_headers.unwrap_or_default(),
+            expose_headers: rule.expose_headers.unwrap_or_default(),
+            max_age_seconds: rule.max_age_seconds.map(|_seconds| _seconds as u32),
+        }
+    }
 }
+
+/// 按 RFC 3986 进行 URL 编码, encode_slash 为 false 时保留 "/"。
+pub(crate) fn encode_uri(s: &str, encode_slash: bool) -> String {
+    let mut result = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(b as char)
+            }
+            b'/' if !encode_slash => result.push('/'),
+            _ => result += &format!("%{:02X}", b),
+        }
+    }
+    result
+}
+
+/// encode_uri 的逆操作, 解码后不是合法的 UTF-8 时返回错误。
+pub(crate) fn decode_uri(s: &str) -> Result<String> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let decoded = match bytes[idx] {
+            b'%' => s
+                .get(idx + 1..idx + 3)
+                .and_then(|_hex| u8::from_str_radix(_hex, 16).ok()),
+            _ => None,
+        };
+        match decoded {
+            Some(_byte) => {
+                result.push(_byte);
+                idx += 3;
+            }
+            None => {
+                result.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+    Ok(String::from_utf8(result)?)
+}
+
+/// copy 请求中的源 Object, 格式为 {bucket}/{key}[?versionId=..], key 需要 URL 编码。
+pub(crate) fn copy_source(bucket: &str, key: &str, version_id: Option<&str>) -> String {
+    let mut source = format!("{}/{}", bucket, encode_uri(key, false));
+    if let Some(_version_id) = version_id {
+        source += "?versionId=";
+        source += _version_id;
+    }
+    source
+}
+
+/// ETag 比较和返回时去掉两端的引号
+pub(crate) fn trim_etag(etag: &str) -> &str {
+    etag.trim_matches('"')
+}
+/// put_multipart 默认的 Part 大小, 8 MiB。
+/// OSS 要求除最后一个 Part 外不小于 100 KiB, S3 要求不小于 5 MiB。
+pub const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// copy_large 默认的 Part 大小, 64 MiB。Part 数量超过 MAX_PARTS 时会自动调大。
+pub const DEFAULT_COPY_PART_SIZE: usize = 64 * 1024 * 1024;
+
+/// put_multipart/copy_large 允许的最小 Part 大小, 5 MiB, 即 S3 的限制。
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// 单个 Multipart Upload 最多包含的 Part 数量。
+pub(crate) const MAX_PARTS: u64 = 10000;
+
+/// 检查 part_size 不小于 MIN_PART_SIZE, 在 initiate_multipart 之前调用。
+pub(crate) fn check_part_size(part_size: usize) -> Result<()> {
+    if part_size < MIN_PART_SIZE {
+        Err(Error::Unsupported {
+            msg: format!("part_size must be at least {} bytes", MIN_PART_SIZE),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// copy_large 同时进行的 upload_part_copy 数量。
+pub(crate) const COPY_LARGE_CONCURRENCY: usize = 4;
+
+/// Multipart Upload 中一个已上传的 Part
+/// part_number:    Part 编号, 1 ~ 10000。
+/// e_tag:          upload_part 返回的 ETag。
+#[derive(Clone, Debug, Default)]
+pub struct CompletedPart {
+    pub part_number: usize,
+    pub e_tag: String,
+}
+
+/// Response to CompleteMultipart
+/// version_id: 开启了版本控制时, 生成的 Object 的版本号。
+#[derive(Clone, Debug, Default)]
+pub struct CompleteMultipartResp {
+    pub e_tag: String,
+    pub version_id: Option<String>,
+}
+
+/// Response to Put/PutStream
+/// e_tag:      Object 的 ETag, 不含引号。
+/// version_id: 开启了版本控制时, 新写入的 Object 的版本号。
+#[derive(Clone, Debug, Default)]
+pub struct PutResp {
+    pub e_tag: String,
+    pub version_id: Option<String>,
+}
+
+impl PutResp {
+    pub(crate) fn from_oss_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
+        Self {
+            e_tag: trim_etag(&header("etag").unwrap_or_default()).to_owned(),
+            version_id: header("x-oss-version-id"),
+        }
+    }
+}
+impl From<CompleteMultipartResp> for PutResp {
+    fn from(resp: CompleteMultipartResp) -> Self {
+        Self {
+            e_tag: trim_etag(&resp.e_tag).to_owned(),
+            version_id: resp.version_id,
+        }
+    }
+}
+
+/// Response to Append
+/// next_position:  下一次 append 的 position, 即当前 Object 的长度。
+/// crc64:          整个 Object 的 CRC64 (ECMA-182)。
+#[derive(Clone, Debug, Default)]
+pub struct AppendResp {
+    pub next_position: u64,
+    pub crc64: Option<u64>,
+}
+
+/// Response to Copy
+/// e_tag:      目标 Object 的 ETag, 不含引号。
+/// version_id: 开启了版本控制时, 目标 Object 的版本号。
+#[derive(Clone, Debug, Default)]
+pub struct CopyResp {
+    pub e_tag: String,
+    pub version_id: Option<String>,
+}
+
+/// 单次批量删除请求最多包含的 Key 数量, OSS 与 S3 均为 1000。
+pub(crate) const MAX_DELETE_KEYS: usize = 1000;
+
+/// Response to DeleteMulti
+/// deleted:    删除成功的 Key, quiet 模式下为空。
+/// errors:     删除失败的 Key 及原因。
+#[derive(Clone, Debug, Default)]
+pub struct DeleteMultiResp {
+    pub deleted: Vec<String>,
+    pub errors: Vec<DeleteError>,
+}
+
+/// 批量删除中单个 Key 的失败原因
+#[derive(Clone, Debug, Default)]
+pub struct DeleteError {
+    pub key: String,
+    pub code: String,
+    pub message: String,
+}
+
 /// List 相关操作的可选参数
 /// 生命周期与传入的 String Literal References 中最短的一致
+/// max_keys:   单次请求返回的最大数量, 默认 1000。
+/// limit:      仅用于 list_all/list_stream, 所有分页合计返回的最大数量, 默认不限制。
+///
+/// v2 为 true 时使用 ListObjectsV2 (list-type=2), 此时 marker 不生效, 改用以下参数:
+/// continuation_token: 上一页返回的 next_continuation_token。
+/// start_after:        从此 Key 之后开始列出, 仅在第一页生效。
+/// fetch_owner:        是否返回 Owner 信息, V2 默认不返回。
+#[derive(Clone, Debug, Default)]
 pub struct ListOptions<'a> {
     pub prefix: Option<&'a str>,
     pub marker: Option<&'a str>,
     pub delimiter: Option<&'a str>,
     pub max_keys: Option<usize>,
+    pub limit: Option<usize>,
+    pub v2: bool,
+    pub continuation_token: Option<&'a str>,
+    pub start_after: Option<&'a str>,
+    pub fetch_owner: Option<bool>,
 }
 impl<'a> ListOptions<'a> {
     /// ListOptions 构造
@@ -260,7 +1544,6 @@ impl<'a> ListOptions<'a> {
     /// ```
     /// let list_opts = awos_rust::ListOptions::new("prefix", "marker", None, 2021);
     /// ```
-
     pub fn new<S1, S2, S3, N>(prefix: S1, marker: S2, delimiter: S3, max_keys: N) -> Self
     where
         S1: Into<Option<&'a str>>,
@@ -273,20 +1556,122 @@ impl<'a> ListOptions<'a> {
             marker: marker.into(),
             delimiter: delimiter.into(),
             max_keys: max_keys.into(),
+            ..Default::default()
+        }
+    }
+    /// 使用 ListObjectsV2 的 ListOptions 构造
+    ///
+    /// #Example
+    /// ```
+    /// let list_opts = awos_rust::ListOptions::new_v2("prefix", None, "start-after", None, 100);
+    /// ```
+    pub fn new_v2<S1, S2, S3, S4, N>(
+        prefix: S1,
+        continuation_token: S2,
+        start_after: S3,
+        delimiter: S4,
+        max_keys: N,
+    ) -> Self
+    where
+        S1: Into<Option<&'a str>>,
+        S2: Into<Option<&'a str>>,
+        S3: Into<Option<&'a str>>,
+        S4: Into<Option<&'a str>>,
+        N: Into<Option<usize>>,
+    {
+        Self {
+            prefix: prefix.into(),
+            delimiter: delimiter.into(),
+            max_keys: max_keys.into(),
+            v2: true,
+            continuation_token: continuation_token.into(),
+            start_after: start_after.into(),
+            ..Default::default()
         }
     }
     pub(crate) fn to_params(&self) -> Vec<(String, Option<String>)> {
-        let mut params_vec = Vec::with_capacity(4);
+        let mut params_vec = Vec::with_capacity(7);
         let mut add_params = |k: &str, v: Option<&str>| {
             if let Some(_v) = v {
                 params_vec.push((k.to_owned(), Some(_v.to_owned())));
             }
         };
         add_params("prefix", self.prefix);
-        add_params("marker", self.marker);
         add_params("delimiter", self.delimiter);
+        if self.v2 {
+            add_params("list-type", Some("2"));
+            add_params("continuation-token", self.continuation_token);
+            add_params("start-after", self.start_after);
+            add_params(
+                "fetch-owner",
+                self.fetch_owner
+                    .map(|_fetch| if _fetch { "true" } else { "false" }),
+            );
+        } else {
+            add_params("marker", self.marker);
+        }
         params_vec.push((
-            "max_keys".to_owned(),
+            "max-keys".to_owned(),
+            Some(self.max_keys.unwrap_or(1000).to_string()),
+        ));
+        params_vec
+    }
+}
+
+/// ListVersions 的可选参数
+/// key_marker:         从此 Key 之后开始列出, 与 version_id_marker 一起使用时从该 Key 的此版本之后开始。
+/// version_id_marker:  上一页返回的 next_version_id_marker。
+/// max_keys:           单次请求返回的版本和删除标记的最大数量, 默认 1000。
+#[derive(Clone, Debug, Default)]
+pub struct ListVersionsOptions<'a> {
+    pub prefix: Option<&'a str>,
+    pub key_marker: Option<&'a str>,
+    pub version_id_marker: Option<&'a str>,
+    pub delimiter: Option<&'a str>,
+    pub max_keys: Option<usize>,
+}
+impl<'a> ListVersionsOptions<'a> {
+    /// ListVersionsOptions 构造, 参数皆为可选。
+    ///
+    /// #Example
+    /// ```
+    /// let list_opts = awos_rust::ListVersionsOptions::new("prefix", "key-marker", None, None, 100);
+    /// ```
+    pub fn new<S1, S2, S3, S4, N>(
+        prefix: S1,
+        key_marker: S2,
+        version_id_marker: S3,
+        delimiter: S4,
+        max_keys: N,
+    ) -> Self
+    where
+        S1: Into<Option<&'a str>>,
+        S2: Into<Option<&'a str>>,
+        S3: Into<Option<&'a str>>,
+        S4: Into<Option<&'a str>>,
+        N: Into<Option<usize>>,
+    {
+        Self {
+            prefix: prefix.into(),
+            key_marker: key_marker.into(),
+            version_id_marker: version_id_marker.into(),
+            delimiter: delimiter.into(),
+            max_keys: max_keys.into(),
+        }
+    }
+    pub(crate) fn to_params(&self) -> Vec<(String, Option<String>)> {
+        let mut params_vec = vec![("versions".to_owned(), None)];
+        let mut add_params = |k: &str, v: Option<&str>| {
+            if let Some(_v) = v {
+                params_vec.push((k.to_owned(), Some(_v.to_owned())));
+            }
+        };
+        add_params("prefix", self.prefix);
+        add_params("key-marker", self.key_marker);
+        add_params("version-id-marker", self.version_id_marker);
+        add_params("delimiter", self.delimiter);
+        params_vec.push((
+            "max-keys".to_owned(),
             Some(self.max_keys.unwrap_or(1000).to_string()),
         ));
         params_vec
@@ -321,3 +1706,229 @@ impl<'a> SignedUrlOptions<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_status_test() {
+        let status: RestoreStatus = r#"ongoing-request="true""#.parse().unwrap();
+        assert!(status.ongoing && status.expiry_date.is_none());
+        let status: RestoreStatus =
+            r#"ongoing-request="false", expiry-date="Sun, 16 Apr 2017 08:12:33 GMT""#
+                .parse()
+                .unwrap();
+        assert!(!status.ongoing);
+        assert_eq!(
+            status.expiry_date.map(|_date| _date.timestamp()),
+            Some(1492330353)
+        );
+        assert!(r#"ongoing-request="false", expiry-date="not a date""#
+            .parse::<RestoreStatus>()
+            .is_err());
+    }
+
+    #[test]
+    fn encode_uri_test() {
+        assert_eq!(encode_uri("a-b_c.d~e", true), "a-b_c.d~e");
+        assert_eq!(encode_uri("dir/awos rust", false), "dir/awos%20rust");
+        assert_eq!(encode_uri("dir/awos rust", true), "dir%2Fawos%20rust");
+        assert_eq!(encode_uri("标签=1&", true), "%E6%A0%87%E7%AD%BE%3D1%26");
+        let opts = PutOrCopyOptions {
+            tags: Some(
+                vec![("team".to_owned(), "awos rust".to_owned())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(opts.tagging().as_deref(), Some("team=awos%20rust"));
+    }
+
+    #[test]
+    fn decode_uri_test() {
+        assert_eq!(decode_uri("dir%2Fawos%20rust").unwrap(), "dir/awos rust");
+        assert_eq!(
+            decode_uri(&encode_uri("软链接/target", true)).unwrap(),
+            "软链接/target"
+        );
+        // 不完整的 % 编码原样保留
+        assert_eq!(decode_uri("100%").unwrap(), "100%");
+        assert_eq!(decode_uri("%zz").unwrap(), "%zz");
+        assert!(decode_uri("%FF").is_err());
+    }
+
+    #[test]
+    fn put_conditions_test() {
+        let opts = PutOrCopyOptions {
+            if_none_match: Some("*"),
+            ..Default::default()
+        };
+        assert!(opts.has_put_conditions());
+        assert!(opts.check_put_conditions(true).is_ok());
+        assert_eq!(opts.as_headers()["x-oss-forbid-overwrite"], "true");
+
+        let opts = PutOrCopyOptions {
+            if_match: Some("\"etag\""),
+            ..Default::default()
+        };
+        assert!(opts.check_put_conditions(false).is_ok());
+        assert_eq!(opts.as_headers()["if-match"], "\"etag\"");
+        assert!(matches!(
+            opts.check_put_conditions(true),
+            Err(Error::Unsupported { .. })
+        ));
+
+        let opts = PutOrCopyOptions {
+            if_none_match: Some("\"etag\""),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.check_put_conditions(false),
+            Err(Error::Unsupported { .. })
+        ));
+
+        let opts = PutOrCopyOptions::default();
+        assert!(!opts.has_put_conditions());
+        assert!(!opts.as_headers().contains_key("x-oss-forbid-overwrite"));
+    }
+
+    #[test]
+    fn sse_headers_test() {
+        let key = [1u8; 32];
+        let values = sse_customer_values(&key);
+        assert_eq!(
+            values,
+            [
+                "AES256".to_owned(),
+                "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=".to_owned(),
+                "4Funlf7OsLF0HL+vKU+fkg==".to_owned(),
+            ]
+        );
+        let opts = PutOrCopyOptions {
+            encryption: Some(ServerSideEncryption::Customer { key: key.to_vec() }),
+            src_sse_customer_key: Some(&key),
+            ..Default::default()
+        };
+        let headers = opts.as_headers();
+        assert_eq!(
+            headers["x-oss-server-side-encryption-customer-key-md5"],
+            values[2]
+        );
+        assert_eq!(
+            headers["x-oss-copy-source-server-side-encryption-customer-key"],
+            values[1]
+        );
+
+        let kms = ServerSideEncryption::Kms {
+            key_id: Some("key-id".to_owned()),
+        };
+        assert_eq!(
+            kms.as_oss_headers(),
+            vec![
+                ("x-oss-server-side-encryption", "KMS".to_owned()),
+                ("x-oss-server-side-encryption-key-id", "key-id".to_owned()),
+            ]
+        );
+        assert_eq!(
+            ServerSideEncryption::Managed.as_oss_headers(),
+            vec![("x-oss-server-side-encryption", "AES256".to_owned())]
+        );
+    }
+
+    #[test]
+    fn byte_range_test() {
+        assert_eq!(ByteRange::Range(0, 3).to_string(), "bytes=0-3");
+        assert_eq!(ByteRange::From(4).to_string(), "bytes=4-");
+        assert_eq!(ByteRange::Suffix(4).to_string(), "bytes=-4");
+        let opts = GetOptions::new(ByteRange::Suffix(4), None, None, None, None);
+        let headers = opts.as_headers();
+        assert_eq!(headers["range"], "bytes=-4");
+        assert_eq!(headers["x-oss-range-behavior"], "standard");
+        assert_eq!(
+            total_size(Some(&"bytes 0-3/44".to_owned()), Some(&"4".to_owned())),
+            Some(44)
+        );
+        assert_eq!(total_size(None, Some(&"44".to_owned())), Some(44));
+    }
+
+    #[test]
+    fn get_conditions_test() {
+        let date = DateTime::parse_from_rfc3339("2017-04-16T08:12:33Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let opts = GetOptions::new(None, "\"a\"".to_owned(), "\"b\"".to_owned(), date, date);
+        let headers = opts.as_headers();
+        assert_eq!(headers["if-match"], "\"a\"");
+        assert_eq!(headers["if-none-match"], "\"b\"");
+        assert_eq!(
+            headers["if-modified-since"],
+            "Sun, 16 Apr 2017 08:12:33 GMT"
+        );
+        assert_eq!(
+            headers["if-unmodified-since"],
+            "Sun, 16 Apr 2017 08:12:33 GMT"
+        );
+        assert!(!headers.contains_key("range"));
+        assert!(matches!(Error::from(304), Error::NotModified));
+        assert!(matches!(Error::from(412), Error::PreconditionFailed));
+    }
+
+    #[test]
+    fn list_options_v2_params_test() {
+        let params = ListOptions::new_v2("a/", "token", "a/b", None, 10).to_params();
+        let get = |k: &str| {
+            params
+                .iter()
+                .find(|(_k, _)| _k == k)
+                .and_then(|(_, _v)| _v.as_deref())
+        };
+        assert_eq!(get("prefix"), Some("a/"));
+        assert_eq!(get("list-type"), Some("2"));
+        assert_eq!(get("continuation-token"), Some("token"));
+        assert_eq!(get("start-after"), Some("a/b"));
+        assert_eq!(get("max-keys"), Some("10"));
+        assert_eq!(get("marker"), None);
+        assert_eq!(get("delimiter"), None);
+    }
+
+    #[test]
+    fn check_part_size_test() {
+        assert!(matches!(check_part_size(0), Err(Error::Unsupported { .. })));
+        assert!(check_part_size(MIN_PART_SIZE - 1).is_err());
+        assert!(check_part_size(MIN_PART_SIZE).is_ok());
+        assert!(check_part_size(DEFAULT_PART_SIZE).is_ok());
+    }
+
+    #[test]
+    fn check_multipart_encryption_test() {
+        let opts = PutOrCopyOptions {
+            encryption: Some(ServerSideEncryption::Kms { key_id: None }),
+            ..Default::default()
+        };
+        assert!(opts.check_multipart_encryption().is_ok());
+        let opts = PutOrCopyOptions {
+            encryption: Some(ServerSideEncryption::Customer { key: vec![0; 32] }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.check_multipart_encryption(),
+            Err(Error::Unsupported { .. })
+        ));
+        let opts = PutOrCopyOptions {
+            src_sse_customer_key: Some(&[0; 32]),
+            ..Default::default()
+        };
+        assert!(opts.check_multipart_encryption().is_err());
+    }
+
+    #[test]
+    fn check_bucket_acl_test() {
+        assert!(check_bucket_acl(Acl::PublicRead).is_ok());
+        assert!(matches!(
+            check_bucket_acl(Acl::Default),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+}
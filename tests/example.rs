@@ -1,6 +1,12 @@
-use awos_rust::{AwosApi, AwosClient, PutOrCopyOptions, SignedUrlOptions};
+use awos_rust::{
+    Acl, AwosApi, AwosClient, ByteRange, ByteStream, CorsRule, CreateBucketOptions, Error,
+    GetOptions, LifecycleRule, LifecycleTime, ListOptions, ListVersionsOptions, MetadataDirective,
+    PutOrCopyOptions, ServerSideEncryption, SignedUrlOptions, StorageClass,
+};
+use chrono::{Duration, Utc};
 
 use std::collections::{HashMap, HashSet};
+use tokio::io::AsyncReadExt;
 
 // use std::error::Error as StdError;
 
@@ -8,6 +14,15 @@ const FILE_NAME: &str = "rust_oss_sdk_test";
 
 const BUF: &[u8] = "This is just a put test".as_bytes();
 
+const COPY_FILE_NAME: &str = "rust_oss_sdk_copy_test";
+
+const STREAM_FILE_NAME: &str = "rust_oss_sdk_stream_test";
+
+const TEST_BUCKET_NAME: &str = "rust-awos-bucket-test";
+
+const MULTIPART_FILE_NAME: &str = "rust_oss_sdk_multipart_test";
+const MULTIPART_PART_SIZE: usize = 5 * 1024 * 1024;
+
 #[tokio::test]
 async fn awos_with_oss_test() {
     let bucket = std::env::var("OSS_BUCKET").unwrap();
@@ -28,17 +43,19 @@ async fn awos_with_oss_test() {
     meta.insert("test-key".to_owned(), "test-val".to_owned());
     let opts = PutOrCopyOptions::new(meta, None, None, None, None);
     let resp = awos_instance.put(FILE_NAME, buf, opts).await;
-    assert!(resp.is_ok());
+    assert!(resp.is_ok() && !resp.unwrap().e_tag.is_empty());
 
     /* GetAsBuffer 不对meta进行过滤 */
-    let resp = awos_instance.get_as_buffer::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
     assert!(resp.is_ok());
     assert!(resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
     assert_eq!(*BUF, *resp_content);
 
     /* GetAsBuffer 用 Vector 进行过滤 */
-    let resp = awos_instance.get_as_buffer(FILE_NAME, vec![]).await;
+    let resp = awos_instance.get_as_buffer(FILE_NAME, vec![], None).await;
     assert!(resp.is_ok());
     assert!(!resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
@@ -47,15 +64,147 @@ async fn awos_with_oss_test() {
     /* GetAsBuffer 用 HashSet 进行过滤 */
     let mut hs = HashSet::new();
     hs.insert("test-key");
-    let resp = awos_instance.get_as_buffer(FILE_NAME, hs).await;
+    let resp = awos_instance.get_as_buffer(FILE_NAME, hs, None).await;
     assert!(resp.is_ok());
     assert!(resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
     assert_eq!(*BUF, *resp_content);
 
     /* Get, 不进行过滤 */
-    let resp = awos_instance.get::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().content == String::from_utf8_lossy(BUF));
+
+    /* Get Range */
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(
+            FILE_NAME,
+            None,
+            GetOptions::new(ByteRange::Range(0, 3), None, None, None, None),
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.total_size, Some(BUF.len() as u64));
+    assert_eq!(BUF[..4], **resp.content);
+
+    /* Get Conditional */
+    let etag = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await
+        .unwrap()
+        .headers["etag"]
+        .to_owned();
+    let opts = GetOptions::new(None, None, etag, None, None);
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, opts)
+        .await;
+    assert!(matches!(resp, Err(Error::NotModified)));
+    let opts = GetOptions::new(None, "\"not-the-etag\"".to_owned(), None, None, None);
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, opts)
+        .await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+
+    /* Put Conditional */
+    let opts = PutOrCopyOptions {
+        forbid_overwrite: Some(true),
+        ..Default::default()
+    };
+    let resp = awos_instance.put(FILE_NAME, BUF.to_vec(), opts).await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+    let etag = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await
+        .unwrap()
+        .headers["etag"]
+        .to_owned();
+    let mut meta = HashMap::new();
+    meta.insert("test-key".to_owned(), "test-val".to_owned());
+    let opts = PutOrCopyOptions {
+        if_match: Some(&etag),
+        ..PutOrCopyOptions::new(meta, None, None, None, None)
+    };
+    let resp = awos_instance.put(FILE_NAME, BUF.to_vec(), opts).await;
+    assert!(resp.is_ok());
+
+    /* GetStream */
+    let resp = awos_instance
+        .get_stream::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok());
+    let mut resp_content = Vec::new();
+    let resp = resp
+        .unwrap()
+        .body
+        .into_async_read()
+        .read_to_end(&mut resp_content)
+        .await;
+    assert!(resp.is_ok());
+    assert_eq!(*BUF, *resp_content);
+
+    /* PutStream */
+    let body = ByteStream::from_async_read_with_size(BUF, BUF.len());
+    let resp = awos_instance.put_stream(STREAM_FILE_NAME, body, None).await;
+    assert!(resp.is_ok());
+
+    /* PutStream, 长度未知 */
+    let body = ByteStream::from_async_read(BUF);
+    let resp = awos_instance.put_stream(STREAM_FILE_NAME, body, None).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(STREAM_FILE_NAME, None, None)
+        .await;
     assert!(resp.is_ok() && resp.unwrap().content == String::from_utf8_lossy(BUF));
+    let resp = awos_instance.del(STREAM_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Multipart Upload, 分为两个 Part */
+    let big_buf = vec![7u8; MULTIPART_PART_SIZE + 1024];
+    let resp = awos_instance
+        .put_multipart(
+            MULTIPART_FILE_NAME,
+            big_buf.as_slice(),
+            MULTIPART_PART_SIZE,
+            None,
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(MULTIPART_FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().content.len() == big_buf.len());
+
+    /* CopyLarge, 分为两个 Part 复制 */
+    let resp = awos_instance
+        .copy_large(
+            MULTIPART_FILE_NAME,
+            COPY_FILE_NAME,
+            MULTIPART_PART_SIZE,
+            None,
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok() && resp.unwrap().content_length == big_buf.len() as u64);
+    let keys = [MULTIPART_FILE_NAME, COPY_FILE_NAME];
+    let resp = awos_instance.del_multi(&keys, true).await;
+    assert!(resp.is_ok() && resp.unwrap().errors.is_empty());
+
+    /* Abort Multipart Upload */
+    let upload_id = awos_instance
+        .initiate_multipart(MULTIPART_FILE_NAME, None)
+        .await
+        .unwrap();
+    let resp = awos_instance
+        .upload_part(MULTIPART_FILE_NAME, &upload_id, 1, BUF)
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .abort_multipart(MULTIPART_FILE_NAME, &upload_id)
+        .await;
+    assert!(resp.is_ok());
 
     /* ListObject, 指定返回为 Vector */
     let resp = awos_instance.list_object(None).await;
@@ -67,6 +216,49 @@ async fn awos_with_oss_test() {
     assert!(resp.is_ok());
     let _hs = resp.unwrap(); //  HashSet with
 
+    /* ListAll, 每页 1 个, 自动翻页 */
+    let opts = ListOptions::new("rust_oss_sdk", None, None, 1);
+    let resp = awos_instance.list_all(opts).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.len() >= 2);
+    let obj = resp.iter().find(|_obj| _obj.key == FILE_NAME);
+    assert!(obj.is_some());
+    let obj = obj.unwrap();
+    assert_eq!(obj.size, BUF.len() as u64);
+    assert!(!obj.e_tag.is_empty() && !obj.e_tag.contains('"'));
+    assert!(obj.last_modified > Utc::now() - Duration::days(1));
+    assert!(!obj.storage_class.is_empty());
+
+    /* ListObjectsV2 */
+    let opts = ListOptions::new_v2("rust_oss_sdk", None, None, None, 1);
+    let resp = awos_instance.list_details(opts).await;
+    assert!(resp.is_ok());
+    let page = resp.unwrap();
+    assert!(page.is_truncated && page.key_count == 1);
+    assert!(!page.next_continuation_token.is_empty());
+
+    /* ListDir, 只列出一层 */
+    let dir_keys = ["rust_oss_sdk_dir/sub/a", "rust_oss_sdk_dir/b"];
+    for key in dir_keys.iter() {
+        let resp = awos_instance.put(*key, BUF.to_vec(), None).await;
+        assert!(resp.is_ok());
+    }
+    let resp = awos_instance.list_dir("rust_oss_sdk_dir").await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.common_prefixes, vec!["rust_oss_sdk_dir/sub/"]);
+    assert!(resp.objects.len() == 1 && resp.objects[0].key == "rust_oss_sdk_dir/b");
+
+    /* DelMulti */
+    let resp = awos_instance.del_multi(&dir_keys, false).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.errors.is_empty());
+    assert!(dir_keys
+        .iter()
+        .all(|key| resp.deleted.contains(&key.to_string())));
+
     /* 获取 Sign_Url */
     let url = awos_instance.sign_url("A", None);
     println!("{:?}", url);
@@ -75,15 +267,196 @@ async fn awos_with_oss_test() {
     println!("{:?}", url);
 
     /* Head */
-    let resp = awos_instance.head(FILE_NAME).await;
-    assert!(resp.is_ok() && resp.unwrap().contains_key("test-key"));
+    let resp = awos_instance.head(FILE_NAME, None).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.user_meta.contains_key("test-key"));
+    assert_eq!(resp.content_length, BUF.len() as u64);
+    assert!(!resp.e_tag.is_empty() && resp.last_modified.is_some());
+
+    /* GetObjectMeta */
+    let resp = awos_instance.get_object_meta(FILE_NAME).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.content_length, BUF.len() as u64);
+    assert!(!resp.e_tag.is_empty());
+
+    /* ListVersions, 未开启版本控制时 version_id 为 "null" */
+    let opts = ListVersionsOptions::new(FILE_NAME, None, None, None, None);
+    let resp = awos_instance.list_versions(opts).await;
+    assert!(resp.is_ok());
+    let latest = resp
+        .unwrap()
+        .versions
+        .into_iter()
+        .find(|_version| _version.key == FILE_NAME && _version.is_latest);
+    assert!(latest.is_some());
+    let latest = latest.unwrap();
+    assert_eq!(latest.size, BUF.len() as u64);
+    let resp = awos_instance
+        .head(FILE_NAME, latest.version_id.as_str())
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().e_tag == latest.e_tag);
+    let opts = GetOptions {
+        version_id: Some(latest.version_id),
+        ..Default::default()
+    };
+    let resp = awos_instance.get(FILE_NAME, vec![], opts).await;
+    assert!(resp.is_ok() && resp.unwrap().content.as_bytes() == BUF);
+
+    /* StorageClass */
+    let opts = PutOrCopyOptions {
+        storage_class: Some(StorageClass::IA),
+        ..Default::default()
+    };
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert!(resp.storage_class == "IA" && resp.restore.is_none());
+    // 非归档类型的 Object 不能 restore
+    let resp = awos_instance.restore(COPY_FILE_NAME, None).await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* ServerSideEncryption */
+    let opts = PutOrCopyOptions {
+        encryption: Some(ServerSideEncryption::Managed),
+        ..Default::default()
+    };
+    let resp = awos_instance.put(COPY_FILE_NAME, BUF.to_vec(), opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert_eq!(resp.server_side_encryption.as_deref(), Some("AES256"));
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Append */
+    let resp = awos_instance.append(COPY_FILE_NAME, 0, BUF, None).await;
+    assert!(resp.is_ok());
+    let next_position = resp.unwrap().next_position;
+    assert_eq!(next_position, BUF.len() as u64);
+    let resp = awos_instance.append(COPY_FILE_NAME, 0, BUF, None).await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+    let resp = awos_instance
+        .append(COPY_FILE_NAME, next_position, BUF, None)
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().next_position == 2 * BUF.len() as u64);
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert_eq!(resp.object_type.as_deref(), Some("Appendable"));
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Symlink */
+    let resp = awos_instance
+        .put_symlink(COPY_FILE_NAME, FILE_NAME, None)
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_symlink(COPY_FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap() == FILE_NAME);
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert!(resp.is_symlink());
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Tagging */
+    let tags: HashMap<_, _> = vec![("team".to_owned(), "awos rust".to_owned())]
+        .into_iter()
+        .collect();
+    let resp = awos_instance.put_tagging(FILE_NAME, tags.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_tagging(FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap() == tags);
+    let resp = awos_instance.delete_tagging(FILE_NAME).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_tagging(FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap().is_empty());
+
+    /* ACL */
+    let resp = awos_instance
+        .put_object_acl(FILE_NAME, Acl::PublicRead)
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_object_acl(FILE_NAME).await;
+    assert!(matches!(resp, Ok(Acl::PublicRead)));
+    let resp = awos_instance.put_object_acl(FILE_NAME, Acl::Default).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_bucket_acl().await;
+    assert!(resp.is_ok());
+
+    /* Bucket */
+    let resp = awos_instance.bucket_exists(&bucket).await;
+    assert!(matches!(resp, Ok(true)));
+    let resp = awos_instance.get_bucket_info(&bucket).await;
+    assert!(resp.is_ok() && resp.unwrap().name == bucket);
+
+    /* Lifecycle */
+    let rules = vec![LifecycleRule {
+        id: "rust-awos-expire".to_owned(),
+        enabled: true,
+        prefix: "rust_awos_lifecycle/".to_owned(),
+        expiration: Some(LifecycleTime::Days(60)),
+        ..Default::default()
+    }];
+    let resp = awos_instance.put_bucket_lifecycle(rules.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_bucket_lifecycle().await;
+    assert!(resp.is_ok() && resp.unwrap() == rules);
+    let resp = awos_instance.delete_bucket_lifecycle().await;
+    assert!(resp.is_ok());
+
+    /* CORS */
+    let rules = vec![CorsRule {
+        allowed_origins: vec!["*".to_owned()],
+        allowed_methods: vec!["PUT".to_owned()],
+        ..Default::default()
+    }];
+    let resp = awos_instance.put_bucket_cors(rules.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_bucket_cors().await;
+    assert!(resp.is_ok() && resp.unwrap() == rules);
+    let resp = awos_instance.delete_bucket_cors().await;
+    assert!(resp.is_ok());
+
+    /* Copy, 默认沿用源 Object 的 meta */
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+    let e_tag = resp.unwrap().e_tag;
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert_eq!(resp.e_tag, e_tag);
+    assert!(resp.user_meta.contains_key("test-key"));
+
+    /* Copy, 替换 meta */
+    let mut meta = HashMap::new();
+    meta.insert("copy-key".to_owned(), "copy-val".to_owned());
+    let opts = PutOrCopyOptions {
+        tags: Some(tags.clone()),
+        ..PutOrCopyOptions::new(meta, "text/plain", None, None, None)
+    };
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert!(resp.user_meta.contains_key("copy-key") && !resp.user_meta.contains_key("test-key"));
+    assert_eq!(resp.content_type.as_deref(), Some("text/plain"));
+    let resp = awos_instance.get_tagging(COPY_FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap() == tags);
+    let opts = PutOrCopyOptions {
+        metadata_directive: Some(MetadataDirective::Copy),
+        ..Default::default()
+    };
+    let resp = awos_instance.copy(COPY_FILE_NAME, FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
 
     /* Del */
-    let resp = awos_instance.del(FILE_NAME).await;
+    let resp = awos_instance.del(FILE_NAME, None).await;
     assert!(resp.is_ok());
 
     /* Check if Del works */
-    let resp = awos_instance.get::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
     assert!(resp.is_err());
     let resp_err = resp.unwrap_err();
     println!("{:#?}", resp_err);
@@ -100,7 +473,8 @@ async fn awos_with_s3_test() {
     let access_key_secret = std::env::var("S3_KEY_SECRET").unwrap_or("minioadmin".to_owned());
 
     let awos_instance =
-        AwosClient::new_with_s3(endpoint, bucket, access_key_id, access_key_secret).unwrap();
+        AwosClient::new_with_s3(endpoint, bucket.clone(), access_key_id, access_key_secret)
+            .unwrap();
 
     /* Put Object */
     let buf = BUF.to_owned().into_boxed_slice();
@@ -108,17 +482,19 @@ async fn awos_with_s3_test() {
     meta.insert("test-key".to_owned(), "test-val".to_owned());
     let opts = PutOrCopyOptions::new(meta, None, None, None, None);
     let resp = awos_instance.put(FILE_NAME, buf, opts).await;
-    assert!(resp.is_ok());
+    assert!(resp.is_ok() && !resp.unwrap().e_tag.is_empty());
 
     /* GetAsBuffer 不对meta进行过滤 */
-    let resp = awos_instance.get_as_buffer::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
     assert!(resp.is_ok());
     assert!(resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
     assert_eq!(*BUF, *resp_content);
 
     /* GetAsBuffer 用 Vector 进行过滤 */
-    let resp = awos_instance.get_as_buffer(FILE_NAME, vec![]).await;
+    let resp = awos_instance.get_as_buffer(FILE_NAME, vec![], None).await;
     assert!(resp.is_ok());
     assert!(!resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
@@ -127,15 +503,139 @@ async fn awos_with_s3_test() {
     /* GetAsBuffer 用 HashSet 进行过滤 */
     let mut hs = HashSet::new();
     hs.insert("test-key");
-    let resp = awos_instance.get_as_buffer(FILE_NAME, hs).await;
+    let resp = awos_instance.get_as_buffer(FILE_NAME, hs, None).await;
     assert!(resp.is_ok());
     assert!(resp.as_ref().unwrap().meta.contains_key("test-key"));
     let resp_content = std::pin::Pin::into_inner(resp.unwrap().content);
     assert_eq!(*BUF, *resp_content);
 
     /* Get, 不进行过滤 */
-    let resp = awos_instance.get::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().content == String::from_utf8_lossy(BUF));
+
+    /* Get Range */
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(
+            FILE_NAME,
+            None,
+            GetOptions::new(ByteRange::Range(0, 3), None, None, None, None),
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.total_size, Some(BUF.len() as u64));
+    assert_eq!(BUF[..4], **resp.content);
+
+    /* Get Conditional */
+    let etag = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await
+        .unwrap()
+        .headers["e_tag"]
+        .to_owned();
+    let opts = GetOptions::new(None, None, etag, None, None);
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, opts)
+        .await;
+    assert!(matches!(resp, Err(Error::NotModified)));
+    let opts = GetOptions::new(None, "\"not-the-etag\"".to_owned(), None, None, None);
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, opts)
+        .await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+
+    /* Put Conditional, S3 的 copy 不支持条件写入 */
+    let opts = PutOrCopyOptions {
+        forbid_overwrite: Some(true),
+        ..Default::default()
+    };
+    let resp = awos_instance.put(FILE_NAME, BUF.to_vec(), opts).await;
+    assert!(matches!(resp, Err(Error::PreconditionFailed)));
+    let opts = PutOrCopyOptions {
+        if_match: Some("\"not-the-etag\""),
+        ..Default::default()
+    };
+    let resp = awos_instance.copy(FILE_NAME, FILE_NAME, opts).await;
+    assert!(matches!(resp, Err(Error::Unsupported { .. })));
+
+    /* GetStream */
+    let resp = awos_instance
+        .get_stream::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok());
+    let mut resp_content = Vec::new();
+    let resp = resp
+        .unwrap()
+        .body
+        .into_async_read()
+        .read_to_end(&mut resp_content)
+        .await;
+    assert!(resp.is_ok());
+    assert_eq!(*BUF, *resp_content);
+
+    /* PutStream */
+    let body = ByteStream::from_async_read_with_size(BUF, BUF.len());
+    let resp = awos_instance.put_stream(STREAM_FILE_NAME, body, None).await;
+    assert!(resp.is_ok());
+
+    /* PutStream, 长度未知 */
+    let body = ByteStream::from_async_read(BUF);
+    let resp = awos_instance.put_stream(STREAM_FILE_NAME, body, None).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(STREAM_FILE_NAME, None, None)
+        .await;
     assert!(resp.is_ok() && resp.unwrap().content == String::from_utf8_lossy(BUF));
+    let resp = awos_instance.del(STREAM_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Multipart Upload, 分为两个 Part */
+    let big_buf = vec![7u8; MULTIPART_PART_SIZE + 1024];
+    let resp = awos_instance
+        .put_multipart(
+            MULTIPART_FILE_NAME,
+            big_buf.as_slice(),
+            MULTIPART_PART_SIZE,
+            None,
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .get_as_buffer::<_, _, Vec<_>, _>(MULTIPART_FILE_NAME, None, None)
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().content.len() == big_buf.len());
+
+    /* CopyLarge, 分为两个 Part 复制 */
+    let resp = awos_instance
+        .copy_large(
+            MULTIPART_FILE_NAME,
+            COPY_FILE_NAME,
+            MULTIPART_PART_SIZE,
+            None,
+        )
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok() && resp.unwrap().content_length == big_buf.len() as u64);
+    let keys = [MULTIPART_FILE_NAME, COPY_FILE_NAME];
+    let resp = awos_instance.del_multi(&keys, true).await;
+    assert!(resp.is_ok() && resp.unwrap().errors.is_empty());
+
+    /* Abort Multipart Upload */
+    let upload_id = awos_instance
+        .initiate_multipart(MULTIPART_FILE_NAME, None)
+        .await
+        .unwrap();
+    let resp = awos_instance
+        .upload_part(MULTIPART_FILE_NAME, &upload_id, 1, BUF)
+        .await;
+    assert!(resp.is_ok());
+    let resp = awos_instance
+        .abort_multipart(MULTIPART_FILE_NAME, &upload_id)
+        .await;
+    assert!(resp.is_ok());
 
     /* ListObject */
     let resp = awos_instance.list_object(None).await;
@@ -143,6 +643,49 @@ async fn awos_with_s3_test() {
     let _v = resp.unwrap(); // Vector with
     println!("{:?}", _v);
 
+    /* ListAll, 每页 1 个, 自动翻页 */
+    let opts = ListOptions::new("rust_oss_sdk", None, None, 1);
+    let resp = awos_instance.list_all(opts).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.len() >= 2);
+    let obj = resp.iter().find(|_obj| _obj.key == FILE_NAME);
+    assert!(obj.is_some());
+    let obj = obj.unwrap();
+    assert_eq!(obj.size, BUF.len() as u64);
+    assert!(!obj.e_tag.is_empty() && !obj.e_tag.contains('"'));
+    assert!(obj.last_modified > Utc::now() - Duration::days(1));
+    assert!(!obj.storage_class.is_empty());
+
+    /* ListObjectsV2 */
+    let opts = ListOptions::new_v2("rust_oss_sdk", None, None, None, 1);
+    let resp = awos_instance.list_details(opts).await;
+    assert!(resp.is_ok());
+    let page = resp.unwrap();
+    assert!(page.is_truncated && page.key_count == 1);
+    assert!(!page.next_continuation_token.is_empty());
+
+    /* ListDir, 只列出一层 */
+    let dir_keys = ["rust_oss_sdk_dir/sub/a", "rust_oss_sdk_dir/b"];
+    for key in dir_keys.iter() {
+        let resp = awos_instance.put(*key, BUF.to_vec(), None).await;
+        assert!(resp.is_ok());
+    }
+    let resp = awos_instance.list_dir("rust_oss_sdk_dir").await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.common_prefixes, vec!["rust_oss_sdk_dir/sub/"]);
+    assert!(resp.objects.len() == 1 && resp.objects[0].key == "rust_oss_sdk_dir/b");
+
+    /* DelMulti */
+    let resp = awos_instance.del_multi(&dir_keys, false).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.errors.is_empty());
+    assert!(dir_keys
+        .iter()
+        .all(|key| resp.deleted.contains(&key.to_string())));
+
     /* 获取 Sign_Url */
     let url = awos_instance.sign_url(FILE_NAME, None);
     println!("{:?}", url);
@@ -151,17 +694,167 @@ async fn awos_with_s3_test() {
     println!("{:?}", url);
 
     /* Head */
-    let resp = awos_instance.head(FILE_NAME).await;
+    let resp = awos_instance.head(FILE_NAME, None).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert!(resp.user_meta.contains_key("test-key"));
+    assert_eq!(resp.content_length, BUF.len() as u64);
+    assert!(!resp.e_tag.is_empty() && resp.last_modified.is_some());
+
+    /* GetObjectMeta */
+    let resp = awos_instance.get_object_meta(FILE_NAME).await;
+    assert!(resp.is_ok());
+    let resp = resp.unwrap();
+    assert_eq!(resp.content_length, BUF.len() as u64);
+    assert!(!resp.e_tag.is_empty());
+
+    /* ListVersions, 未开启版本控制时 version_id 为 "null" */
+    let opts = ListVersionsOptions::new(FILE_NAME, None, None, None, None);
+    let resp = awos_instance.list_versions(opts).await;
+    assert!(resp.is_ok());
+    let latest = resp
+        .unwrap()
+        .versions
+        .into_iter()
+        .find(|_version| _version.key == FILE_NAME && _version.is_latest);
+    assert!(latest.is_some());
+    let latest = latest.unwrap();
+    assert_eq!(latest.size, BUF.len() as u64);
+    let resp = awos_instance
+        .head(FILE_NAME, latest.version_id.as_str())
+        .await;
+    assert!(resp.is_ok() && resp.unwrap().e_tag == latest.e_tag);
+    let opts = GetOptions {
+        version_id: Some(latest.version_id),
+        ..Default::default()
+    };
+    let resp = awos_instance.get(FILE_NAME, vec![], opts).await;
+    assert!(resp.is_ok() && resp.unwrap().content.as_bytes() == BUF);
+
+    /* StorageClass, minio 只支持 STANDARD */
+    let opts = PutOrCopyOptions {
+        storage_class: Some(StorageClass::Standard),
+        ..Default::default()
+    };
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert!(resp.storage_class == "STANDARD" && resp.restore.is_none());
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+
+    /* Append, S3 不支持 */
+    let resp = awos_instance.append(COPY_FILE_NAME, 0, BUF, None).await;
+    assert!(matches!(resp, Err(Error::Unsupported { .. })));
+
+    /* Symlink, S3 不支持 */
+    let resp = awos_instance
+        .put_symlink(COPY_FILE_NAME, FILE_NAME, None)
+        .await;
+    assert!(matches!(resp, Err(Error::Unsupported { .. })));
+
+    /* Tagging */
+    let tags: HashMap<_, _> = vec![("team".to_owned(), "awos rust".to_owned())]
+        .into_iter()
+        .collect();
+    let resp = awos_instance.put_tagging(FILE_NAME, tags.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_tagging(FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap() == tags);
+    let resp = awos_instance.delete_tagging(FILE_NAME).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_tagging(FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap().is_empty());
+
+    /* ACL, minio 只支持 private */
+    let resp = awos_instance.put_object_acl(FILE_NAME, Acl::Private).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_object_acl(FILE_NAME).await;
+    assert!(matches!(resp, Ok(Acl::Private)));
+
+    /* Bucket */
+    let resp = awos_instance.bucket_exists(&bucket).await;
+    assert!(matches!(resp, Ok(true)));
+    let resp = awos_instance.get_bucket_info(&bucket).await;
+    assert!(resp.is_ok() && resp.unwrap().name == bucket);
+    let opts = CreateBucketOptions {
+        acl: Some(Acl::Private),
+        ..Default::default()
+    };
+    let resp = awos_instance.create_bucket(TEST_BUCKET_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.delete_bucket(TEST_BUCKET_NAME).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.bucket_exists(TEST_BUCKET_NAME).await;
+    assert!(matches!(resp, Ok(false)));
+
+    /* Lifecycle */
+    let rules = vec![LifecycleRule {
+        id: "rust-awos-expire".to_owned(),
+        enabled: true,
+        prefix: "rust_awos_lifecycle/".to_owned(),
+        expiration: Some(LifecycleTime::Days(60)),
+        ..Default::default()
+    }];
+    let resp = awos_instance.put_bucket_lifecycle(rules.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_bucket_lifecycle().await;
+    assert!(resp.is_ok() && resp.unwrap() == rules);
+    let resp = awos_instance.delete_bucket_lifecycle().await;
+    assert!(resp.is_ok());
+
+    /* CORS */
+    let rules = vec![CorsRule {
+        allowed_origins: vec!["*".to_owned()],
+        allowed_methods: vec!["PUT".to_owned()],
+        ..Default::default()
+    }];
+    let resp = awos_instance.put_bucket_cors(rules.clone()).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.get_bucket_cors().await;
+    assert!(resp.is_ok() && resp.unwrap() == rules);
+    let resp = awos_instance.delete_bucket_cors().await;
+    assert!(resp.is_ok());
+
+    /* Copy, 默认沿用源 Object 的 meta */
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, None).await;
+    assert!(resp.is_ok());
+    let e_tag = resp.unwrap().e_tag;
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert_eq!(resp.e_tag, e_tag);
+    assert!(resp.user_meta.contains_key("test-key"));
+
+    /* Copy, 替换 meta */
+    let mut meta = HashMap::new();
+    meta.insert("copy-key".to_owned(), "copy-val".to_owned());
+    let opts = PutOrCopyOptions {
+        tags: Some(tags.clone()),
+        ..PutOrCopyOptions::new(meta, "text/plain", None, None, None)
+    };
+    let resp = awos_instance.copy(FILE_NAME, COPY_FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.head(COPY_FILE_NAME, None).await.unwrap();
+    assert!(resp.user_meta.contains_key("copy-key") && !resp.user_meta.contains_key("test-key"));
+    assert_eq!(resp.content_type.as_deref(), Some("text/plain"));
+    let resp = awos_instance.get_tagging(COPY_FILE_NAME).await;
+    assert!(resp.is_ok() && resp.unwrap() == tags);
+    let opts = PutOrCopyOptions {
+        metadata_directive: Some(MetadataDirective::Copy),
+        ..Default::default()
+    };
+    let resp = awos_instance.copy(COPY_FILE_NAME, FILE_NAME, opts).await;
+    assert!(resp.is_ok());
+    let resp = awos_instance.del(COPY_FILE_NAME, None).await;
     assert!(resp.is_ok());
-    println!("{:#?}", resp);
-    // assert!(resp.is_ok() && resp.unwrap().contains_key("test-key"));
 
     /* Del */
-    let resp = awos_instance.del(FILE_NAME).await;
+    let resp = awos_instance.del(FILE_NAME, None).await;
     assert!(resp.is_ok());
 
     /* Check if Del works */
-    let resp = awos_instance.get::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let resp = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
     assert!(resp.is_err());
     // assert!(resp.unwrap_err().source().unwrap());
 
@@ -173,6 +866,8 @@ async fn awos_with_s3_test() {
     let awos_instance =
         AwosClient::new_with_s3(endpoint, bucket, access_key_id, access_key_secret).unwrap();
 
-    let ret = awos_instance.get::<_, _, Vec<_>>(FILE_NAME, None).await;
+    let ret = awos_instance
+        .get::<_, _, Vec<_>, _>(FILE_NAME, None, None)
+        .await;
     println!("{:#?}", ret);
 }